@@ -0,0 +1,499 @@
+//! Cellular automata over grids: every cell is updated at once from the
+//! values of its neighbours.
+
+use crate::grid::{Boundary, Grid, Neighborhood, Offset, Pos, SparseGrid, Window};
+#[cfg(feature = "parallel")]
+use crate::parallel;
+use std::collections::HashSet;
+use std::mem;
+
+/// Steps a grid, honouring its boundary when looking past the edges.
+#[derive(Clone, Debug)]
+pub struct Automaton<T> {
+    grid: Grid<T>,
+    // Only needed by rules over neighbourhoods, not by window rules
+    neighborhood: Option<Neighborhood>,
+    // Whether the grid is a window on an infinite plane of the value of its
+    // fixed boundary
+    infinite: bool,
+    steps: usize,
+    // The next generation is built here and then swapped with the grid
+    buffer: Vec<T>,
+    values: Vec<Option<T>>,
+}
+
+impl<T: Clone> Automaton<T> {
+    /// An automaton over `grid`, whose boundary decides the neighbours of
+    /// cells on its edges. With a `Clip` boundary, those cells are missing
+    /// some neighbours.
+    pub fn new(grid: Grid<T>, neighborhood: &Neighborhood) -> Automaton<T> {
+        Automaton {
+            neighborhood: Some(neighborhood.clone()),
+            ..Automaton::windowed(grid)
+        }
+    }
+
+    /// An automaton over `grid` with no neighbourhood, which can only be
+    /// stepped with `step_window`.
+    pub fn windowed(grid: Grid<T>) -> Automaton<T> {
+        Automaton {
+            grid,
+            neighborhood: None,
+            infinite: false,
+            steps: 0,
+            buffer: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// An automaton where `grid` is a window on an infinite plane of
+    /// `background`. The window grows to take in every cell that could
+    /// change, and the background itself is updated by the rule each step.
+    pub fn infinite(grid: Grid<T>, neighborhood: &Neighborhood, background: T) -> Automaton<T> {
+        Automaton {
+            neighborhood: Some(neighborhood.clone()),
+            ..Automaton::infinite_windowed(grid, background)
+        }
+    }
+
+    /// Like `infinite`, but with no neighbourhood, like `windowed`.
+    pub fn infinite_windowed(grid: Grid<T>, background: T) -> Automaton<T> {
+        Automaton {
+            infinite: true,
+            ..Automaton::windowed(grid.with_boundary(Boundary::Fixed(background)))
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        self.grid.boundary()
+    }
+
+    /// The number of times `step` has been called.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The background of an infinite automaton.
+    fn background(&self) -> Option<T> {
+        match (self.infinite, self.grid.boundary()) {
+            (true, Boundary::Fixed(background)) => Some(background.clone()),
+            _ => None,
+        }
+    }
+
+    /// Pads the grid with `reach` cells of background on every side, so that
+    /// it holds every cell whose neighbourhood reaches into the current grid.
+    fn grow(&mut self, background: &T, reach: usize) {
+        if reach == 0 {
+            return;
+        }
+        let width = self.grid.width() + 2 * reach;
+        let height = self.grid.height() + 2 * reach;
+        let mut grown = Grid::new(vec![background.clone(); width * height], width)
+            .with_boundary(self.grid.boundary().clone());
+        let shift = Offset::new(reach as isize, reach as isize);
+        self.grid
+            .positions()
+            .zip(self.grid.iter())
+            .for_each(|(pos, value)| grown[pos + shift] = value.clone());
+        self.grid = grown;
+    }
+
+    /// The neighbourhood that `step` and `cascade` need.
+    fn neighborhood(&self) -> &Neighborhood {
+        self.neighborhood
+            .as_ref()
+            .expect("a windowed automaton has no neighbourhood to step or cascade through")
+    }
+
+    /// How far the neighbourhood reaches from a cell in either axis.
+    fn reach(&self) -> usize {
+        self.neighborhood()
+            .offsets()
+            .iter()
+            .map(|offset| offset.dx.abs().max(offset.dy.abs()))
+            .max()
+            .unwrap_or(0) as usize
+    }
+
+    /// Replaces the grid with the `next` generation, and the background of an
+    /// infinite automaton with `background`.
+    fn advance(&mut self, next: Vec<T>, background: Option<T>) {
+        let boundary = match background {
+            Some(background) => Boundary::Fixed(background),
+            None => self.grid.boundary().clone(),
+        };
+        let next = Grid::new(next, self.grid.width()).with_boundary(boundary);
+        self.buffer = mem::replace(&mut self.grid, next).into_elements();
+        self.steps += 1;
+    }
+
+    /// Runs one step, where `cell(values, grid, pos)` gives the next value
+    /// of a cell, using `values` as scratch space for its neighbours, and
+    /// `map` applies it to every cell, one after another or in parallel. An
+    /// infinite grid is grown by `reach` first, and its background is
+    /// stepped by applying `cell` to a plane of nothing but background.
+    fn evolve<F>(
+        &mut self,
+        reach: usize,
+        mut cell: F,
+        map: impl FnOnce(&Grid<T>, &mut F, Vec<T>, &mut Vec<Option<T>>) -> Vec<T>,
+    ) where
+        F: FnMut(&mut Vec<Option<T>>, &Grid<T>, Pos) -> T,
+    {
+        let background = self.background();
+        if let Some(background) = &background {
+            self.grow(background, reach);
+        }
+        let mut values = mem::take(&mut self.values);
+        let next = map(
+            &self.grid,
+            &mut cell,
+            mem::take(&mut self.buffer),
+            &mut values,
+        );
+        let background = background.map(|background| {
+            let plane =
+                Grid::new(vec![background.clone()], 1).with_boundary(Boundary::Fixed(background));
+            cell(&mut values, &plane, Pos::ORIGIN)
+        });
+        self.values = values;
+        self.advance(next, background);
+    }
+
+    /// Updates every cell at once to `rule(cell, neighbours)`, where there is
+    /// a neighbour for each of the neighbourhood's offsets, in their order.
+    /// Neighbours cut off by a `Clip` boundary are `None`.
+    pub fn step(&mut self, mut rule: impl FnMut(&T, &[Option<T>]) -> T) {
+        let (neighborhood, reach) = (self.neighborhood().clone(), self.reach());
+        let cell = |values: &mut Vec<Option<T>>, grid: &Grid<T>, pos| {
+            neighbor_values(values, grid, pos, &neighborhood);
+            rule(&grid[pos], values)
+        };
+        self.evolve(reach, cell, map_sequential);
+    }
+
+    /// Like `step`, but passing `rule` the `width` by `height` window centred
+    /// on each cell rather than its neighbourhood.
+    pub fn step_window(
+        &mut self,
+        width: usize,
+        height: usize,
+        mut rule: impl FnMut(&Window<'_, T>) -> T,
+    ) {
+        let cell = |_: &mut _, grid: &Grid<T>, pos| rule(&grid.window(pos, width, height));
+        self.evolve(width.max(height) / 2, cell, map_sequential);
+    }
+
+    /// Like `step`, but updating the cells in parallel.
+    #[cfg(feature = "parallel")]
+    pub fn par_step(&mut self, rule: impl Fn(&T, &[Option<T>]) -> T + Sync)
+    where
+        T: Send + Sync,
+    {
+        let (neighborhood, reach) = (self.neighborhood().clone(), self.reach());
+        let cell = |values: &mut Vec<Option<T>>, grid: &Grid<T>, pos| {
+            neighbor_values(values, grid, pos, &neighborhood);
+            rule(&grid[pos], values)
+        };
+        self.evolve(reach, cell, map_parallel);
+    }
+
+    /// Like `step_window`, but updating the cells in parallel.
+    #[cfg(feature = "parallel")]
+    pub fn par_step_window(
+        &mut self,
+        width: usize,
+        height: usize,
+        rule: impl Fn(&Window<'_, T>) -> T + Sync,
+    ) where
+        T: Send + Sync,
+    {
+        let cell = |_: &mut _, grid: &Grid<T>, pos| rule(&grid.window(pos, width, height));
+        self.evolve(width.max(height) / 2, cell, map_parallel);
+    }
+
+    /// Spreads events through the grid, for rules where a cell triggering
+    /// affects its neighbours within the same step. Every cell for which
+    /// `fires` holds fires once, calling `spread` on each of its neighbours,
+    /// which may then fire too. Returns the cells that fired, in order.
+    ///
+    /// Panics if the automaton is windowed, as does `step`.
+    pub fn cascade(
+        &mut self,
+        mut fires: impl FnMut(&T) -> bool,
+        mut spread: impl FnMut(&mut T),
+    ) -> Vec<Pos> {
+        let neighborhood = self.neighborhood().clone();
+        let mut fired: Vec<Pos> = self
+            .grid
+            .positions()
+            .filter(|&pos| fires(&self.grid[pos]))
+            .collect();
+        let mut seen: HashSet<Pos> = fired.iter().copied().collect();
+        let mut neighbors = Vec::new();
+        let mut next = 0;
+        while let Some(&pos) = fired.get(next) {
+            next += 1;
+            // Each neighbour is spread to once, even where several offsets
+            // wrap or reflect onto it, as in `Grid::neighbors_of`
+            neighbors.clear();
+            neighbors.extend(self.grid.neighbors_of(pos, &neighborhood));
+            for &neighbor in &neighbors {
+                spread(&mut self.grid[neighbor]);
+                if !seen.contains(&neighbor) && fires(&self.grid[neighbor]) {
+                    seen.insert(neighbor);
+                    fired.push(neighbor);
+                }
+            }
+        }
+        fired
+    }
+}
+
+/// Replaces `values` with the values of the neighbours of `pos`.
+fn neighbor_values<T: Clone>(
+    values: &mut Vec<Option<T>>,
+    grid: &Grid<T>,
+    pos: Pos,
+    neighborhood: &Neighborhood,
+) {
+    values.clear();
+    values.extend(
+        grid.neighbor_values(pos, neighborhood)
+            .map(|value| value.cloned()),
+    );
+}
+
+/// Maps every cell one after another, into the reused `buffer`.
+fn map_sequential<T>(
+    grid: &Grid<T>,
+    cell: &mut impl FnMut(&mut Vec<Option<T>>, &Grid<T>, Pos) -> T,
+    mut buffer: Vec<T>,
+    values: &mut Vec<Option<T>>,
+) -> Vec<T> {
+    buffer.clear();
+    buffer.extend(grid.positions().map(|pos| cell(values, grid, pos)));
+    buffer
+}
+
+/// Maps the cells in parallel, into the reused `buffer`, with scratch space
+/// for the neighbours on each thread.
+#[cfg(feature = "parallel")]
+fn map_parallel<T: Clone + Send + Sync>(
+    grid: &Grid<T>,
+    cell: &mut (impl Fn(&mut Vec<Option<T>>, &Grid<T>, Pos) -> T + Sync),
+    mut buffer: Vec<T>,
+    _values: &mut Vec<Option<T>>,
+) -> Vec<T> {
+    // The buffer only needs the right length, as every cell is overwritten
+    if buffer.len() != grid.size() {
+        buffer.clear();
+        buffer.extend_from_slice(grid.elements());
+    }
+    let cell = &*cell;
+    parallel::fill_with(&mut buffer, Vec::new, |values, index| {
+        cell(values, grid, grid.pos_of(index).unwrap())
+    });
+    buffer
+}
+
+/// Steps an unbounded sparse grid once, where cells that have not been set
+/// hold the background and the background itself follows the rule.
+pub fn step_sparse<T: Clone + PartialEq>(
+    grid: &SparseGrid<T>,
+    neighborhood: &Neighborhood,
+    mut rule: impl FnMut(&T, &[T]) -> T,
+) -> SparseGrid<T> {
+    let offsets = neighborhood.offsets();
+    let background = rule(
+        grid.background(),
+        &vec![grid.background().clone(); offsets.len()],
+    );
+    let mut next = SparseGrid::new(background);
+
+    // Only set cells and those whose neighbourhood includes one can differ
+    // from the background
+    let candidates: HashSet<Pos> = grid
+        .iter()
+        .flat_map(|(&pos, _)| offsets.iter().map(move |&offset| pos - offset).chain([pos]))
+        .collect();
+    let mut values = Vec::with_capacity(offsets.len());
+    for pos in candidates {
+        values.clear();
+        values.extend(offsets.iter().map(|&offset| grid.get(pos + offset).clone()));
+        let value = rule(grid.get(pos), &values);
+        if value != *next.background() {
+            next.set(pos, value);
+        }
+    }
+    next
+}
+
+#[cfg(test)]
+mod automaton_tests {
+    use super::*;
+
+    /// Conway's game of life
+    fn life(&alive: &bool, neighbors: &[Option<bool>]) -> bool {
+        let count = neighbors.iter().filter(|&&n| n == Some(true)).count();
+        count == 3 || (alive && count == 2)
+    }
+
+    fn cells(text: &str) -> Grid<bool> {
+        Grid::parse_with(text, |c| Ok::<bool, &str>(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_fixed() {
+        let mut blinker = Automaton::new(
+            cells(".....\n..#..\n..#..\n..#..\n.....").with_boundary(Boundary::Fixed(false)),
+            &Neighborhood::Moore,
+        );
+        blinker.step(life);
+        assert_eq!(
+            blinker.grid().elements(),
+            cells(".....\n.....\n.###.\n.....\n.....").elements()
+        );
+        blinker.step(life);
+        assert_eq!(
+            blinker.grid().elements(),
+            cells(".....\n..#..\n..#..\n..#..\n.....").elements()
+        );
+        assert_eq!(blinker.steps(), 2);
+    }
+    #[test]
+    fn test_clip() {
+        // Each cell takes the value of the cell to its left, and cells on the
+        // left edge are cleared rather than reading from a shorter list
+        let mut automaton = Automaton::new(cells("#.\n.#"), &Neighborhood::VonNeumann);
+        automaton.step(|_, neighbors| neighbors[3].unwrap_or(false));
+        assert_eq!(automaton.grid().elements(), cells(".#\n..").elements());
+    }
+    #[test]
+    fn test_wrap() {
+        let mut blinker = Automaton::new(
+            cells("#..\n#..\n#..").with_boundary(Boundary::Wrap),
+            &Neighborhood::VonNeumann,
+        );
+        // Each cell takes the value of the cell to its left
+        blinker.step(|_, neighbors| neighbors[3].unwrap());
+        assert_eq!(blinker.grid().elements(), cells(".#.\n.#.\n.#.").elements());
+        blinker.step(|_, neighbors| neighbors[3].unwrap());
+        blinker.step(|_, neighbors| neighbors[3].unwrap());
+        assert_eq!(blinker.grid().elements(), cells("#..\n#..\n#..").elements());
+    }
+    #[test]
+    fn test_infinite() {
+        let mut automaton = Automaton::infinite(cells("#"), &Neighborhood::VonNeumann, false);
+        // Cells light up next to lit cells, and the background inverts
+        automaton.step(|&lit, neighbors| !lit && neighbors.contains(&Some(true)));
+        assert_eq!(
+            automaton.grid().elements(),
+            cells(".#.\n#.#\n.#.").elements()
+        );
+        assert_eq!(automaton.boundary(), &Boundary::Fixed(false));
+        automaton.step(|&lit, _| !lit);
+        assert_eq!(automaton.grid().width(), 5);
+        assert_eq!(automaton.boundary(), &Boundary::Fixed(true));
+    }
+    #[test]
+    fn test_step_window() {
+        let mut automaton = Automaton::infinite_windowed(cells(".#"), false);
+        // Cells light up if anything in the 2x2 block from them is lit
+        automaton.step_window(2, 2, |window| window.cells().flatten().any(|&lit| lit));
+        assert_eq!(
+            automaton.grid().elements(),
+            cells(".##.\n.##.\n....").elements()
+        );
+        assert_eq!(automaton.boundary(), &Boundary::Fixed(false));
+    }
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_step() {
+        let glider = cells(".#......\n..#.....\n###.....\n........");
+        let mut sequential = Automaton::new(glider.clone(), &Neighborhood::Moore);
+        let mut parallel = Automaton::new(glider.clone(), &Neighborhood::Moore);
+        for _ in 0..4 {
+            sequential.step(life);
+            parallel.par_step(life);
+        }
+        assert_eq!(parallel.grid(), sequential.grid());
+
+        let mut sequential = Automaton::infinite_windowed(glider.clone(), false);
+        let mut parallel = Automaton::infinite_windowed(glider, false);
+        let rule =
+            |window: &Window<'_, bool>| window.cells().flatten().filter(|&&lit| lit).count() == 1;
+        for _ in 0..3 {
+            sequential.step_window(3, 3, rule);
+            parallel.par_step_window(3, 3, rule);
+        }
+        assert_eq!(parallel.grid(), sequential.grid());
+    }
+    #[test]
+    #[should_panic(expected = "a windowed automaton has no neighbourhood")]
+    fn test_windowed_step() {
+        let mut automaton = Automaton::windowed(cells("#."));
+        automaton.step_window(1, 1, |window| !window.get(0, 0).unwrap());
+        assert_eq!(automaton.grid().elements(), cells(".#").elements());
+        automaton.step(life);
+    }
+    #[test]
+    fn test_cascade() {
+        let mut automaton =
+            Automaton::new(Grid::new(vec![9, 8, 1, 7], 4), &Neighborhood::VonNeumann);
+        let fired = automaton.cascade(|&energy| energy > 8, |energy| *energy += 1);
+        assert_eq!(fired, vec![Pos::new(0, 0), Pos::new(1, 0)]);
+        assert_eq!(automaton.grid().elements(), &vec![10, 9, 2, 7]);
+    }
+    #[test]
+    fn test_cascade_reflect() {
+        // Reflecting must not lead a cell back onto itself, and the
+        // neighbour reached both directly and by reflection gets the spread
+        // energy once
+        let mut automaton = Automaton::new(
+            Grid::new(vec![9, 0], 2).with_boundary(Boundary::Reflect),
+            &Neighborhood::VonNeumann,
+        );
+        automaton.cascade(|&energy| energy > 8, |energy| *energy += 1);
+        assert_eq!(automaton.grid().elements(), &vec![9, 1]);
+        let mut single = Automaton::new(
+            Grid::new(vec![9], 1).with_boundary(Boundary::Reflect),
+            &Neighborhood::Moore,
+        );
+        single.cascade(|&energy| energy > 8, |energy| *energy += 1);
+        assert_eq!(single.grid().elements(), &vec![9]);
+    }
+    #[test]
+    fn test_cascade_wrap() {
+        // In a wrapped 2x2 grid every offset of the Moore neighbourhood
+        // lands on one of the three other cells, each bumped once
+        let mut automaton = Automaton::new(
+            Grid::new(vec![9, 0, 0, 0], 2).with_boundary(Boundary::Wrap),
+            &Neighborhood::Moore,
+        );
+        let fired = automaton.cascade(|&energy| energy > 8, |energy| *energy += 1);
+        assert_eq!(fired, vec![Pos::ORIGIN]);
+        assert_eq!(automaton.grid().elements(), &vec![9, 1, 1, 1]);
+    }
+    #[test]
+    fn test_sparse() {
+        let mut glider = SparseGrid::from_grid(&cells(".#.\n..#\n###"), Pos::ORIGIN, false);
+        for _ in 0..4 {
+            glider = step_sparse(&glider, &Neighborhood::Moore, |alive, neighbors| {
+                let neighbors: Vec<_> = neighbors.iter().copied().map(Some).collect();
+                life(alive, &neighbors)
+            });
+        }
+        assert_eq!(glider.len(), 5);
+        assert_eq!(glider.bounds(), Some((Pos::new(1, 1), Pos::new(3, 3))));
+    }
+}
@@ -1,6 +1,6 @@
 use std::error::Error;
 use std::fmt;
-use std::ops::{Index, IndexMut, Range};
+use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -20,6 +20,12 @@ impl fmt::Display for Bits {
     }
 }
 
+impl Default for Bits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Bits {
     pub fn new() -> Self {
         Self { bits: Vec::new() }
@@ -36,8 +42,7 @@ impl Bits {
                 bits
             })
             .for_each(|b: Bits| bits.append(&mut b.bits.clone()));
-        let bits = Bits::from(bits);
-        bits
+        Bits::from(bits)
     }
 
     /// Gets Bits where start is the left-most bit wanted and len is the amount of bits
@@ -56,6 +61,10 @@ impl Bits {
         self.bits.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
     pub fn pad(&mut self, pad_to: usize) {
         let padding = pad_to - self.len();
         if padding > 0 {
@@ -94,7 +103,7 @@ impl From<usize> for Bits {
         let mut num = num;
         let mut bits = Vec::new();
         let mut started = false;
-        for n in (0..64 as u32).rev() {
+        for n in (0..64_u32).rev() {
             let power = 2usize.pow(n);
             if num >= power {
                 bits.push(true);
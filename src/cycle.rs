@@ -0,0 +1,140 @@
+//! Finding where a repeated simulation starts to loop, so that its state far
+//! in the future can be found without simulating every step.
+
+/// States from step `start` onwards repeat every `length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle reached by repeatedly applying `step` to `initial` using
+/// Brent's algorithm, which takes fewer steps than Floyd's. Never returns if
+/// the states don't repeat.
+pub fn brent<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by teleporting the tortoise to the hare at each power
+    // of two until the hare laps it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a cycle ahead, they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle reached by repeatedly applying `step` to `initial` using
+/// Floyd's tortoise and hare. Never returns if the states don't repeat.
+pub fn floyd<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, skipping over whole cycles.
+pub fn state_at<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(initial, &mut step);
+    (0..cycle.equivalent(n)).fold(initial.clone(), |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod cycle_tests {
+    use super::*;
+    use crate::automaton::Automaton;
+    use crate::grid::{Grid, Neighborhood};
+    use std::collections::HashSet;
+
+    fn next(&x: &usize) -> usize {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_cycle() {
+        // 3 -> 10 -> 101 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101
+        let expected = Cycle {
+            start: 2,
+            length: 6,
+        };
+        assert_eq!(brent(&3, next), expected);
+        assert_eq!(floyd(&3, next), expected);
+        assert_eq!(expected.equivalent(1), 1);
+        assert_eq!(expected.equivalent(14), 2);
+        assert_eq!(state_at(&3, next, 1_000_000_000), 5);
+    }
+    #[test]
+    fn test_grid_states() {
+        let blinker: Grid<bool> =
+            Grid::parse_with("...\n###\n...", |c| Ok::<bool, &str>(c == '#')).unwrap();
+        let step = |grid: &Grid<bool>| {
+            let mut automaton = Automaton::new(grid.clone(), &Neighborhood::Moore);
+            automaton.step(|&alive, neighbors| {
+                let count = neighbors.iter().filter(|&&n| n == Some(true)).count();
+                count == 3 || (alive && count == 2)
+            });
+            automaton.grid().clone()
+        };
+        assert_eq!(
+            brent(&blinker, step),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+        assert_eq!(state_at(&blinker, step, 1001), blinker.transpose());
+
+        let states: HashSet<Grid<bool>> =
+            [blinker.clone(), step(&blinker), step(&step(&blinker))].into();
+        assert_eq!(states.len(), 2);
+    }
+}
@@ -0,0 +1,360 @@
+//! Straight lines on the integer grid: drawing them cell by cell, counting
+//! where they meet, and casting rays across a `Grid`.
+
+use crate::grid::{Direction, Grid, Offset, Pos};
+use std::collections::HashMap;
+
+/// The straight line between two positions, including both ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub start: Pos,
+    pub end: Pos,
+}
+
+impl Segment {
+    pub fn new(start: Pos, end: Pos) -> Segment {
+        Segment { start, end }
+    }
+
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// Whether the segment is at exactly 45°.
+    pub fn is_diagonal(&self) -> bool {
+        let Offset { dx, dy } = self.end - self.start;
+        dx != 0 && dx.abs() == dy.abs()
+    }
+
+    /// The smallest step along the segment between positions exactly on it.
+    fn step(&self) -> Offset {
+        let Offset { dx, dy } = self.end - self.start;
+        let divisor = gcd(dx, dy).max(1);
+        Offset::new(dx / divisor, dy / divisor)
+    }
+
+    /// The positions lying exactly on the segment, from start to end. For
+    /// axis aligned and diagonal segments these are every cell it crosses.
+    pub fn lattice_points(&self) -> impl Iterator<Item = Pos> {
+        let (start, step) = (self.start, self.step());
+        let count = gcd(self.end.x - self.start.x, self.end.y - self.start.y) + 1;
+        (0..count).map(move |i| start + step * i)
+    }
+
+    /// The cells of the segment drawn with Bresenham's algorithm: one per
+    /// step along its longer axis, from start to end.
+    pub fn bresenham(&self) -> Vec<Pos> {
+        let Offset { dx, dy } = self.end - self.start;
+        let (step_x, step_y) = (dx.signum(), dy.signum());
+        let (dx, dy) = (dx.abs(), -dy.abs());
+        let mut error = dx + dy;
+        let mut pos = self.start;
+        let mut cells = vec![pos];
+        while pos != self.end {
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                pos.x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                pos.y += step_y;
+            }
+            cells.push(pos);
+        }
+        cells
+    }
+
+    /// Every cell the segment touches, treating cells as unit squares centred
+    /// on their positions, from start to end. Where the segment passes
+    /// exactly through a corner, both cells beside the corner are included.
+    pub fn supercover(&self) -> Vec<Pos> {
+        let Offset { dx, dy } = self.end - self.start;
+        let (step_x, step_y) = (dx.signum(), dy.signum());
+        let (nx, ny) = (dx.abs(), dy.abs());
+        let mut pos = self.start;
+        let mut cells = vec![pos];
+        let (mut ix, mut iy) = (0, 0);
+        while ix < nx || iy < ny {
+            // Which cell edge the segment crosses next, comparing the
+            // distances to the next vertical and horizontal edges
+            match ((1 + 2 * ix) * ny).cmp(&((1 + 2 * iy) * nx)) {
+                std::cmp::Ordering::Less => {
+                    pos.x += step_x;
+                    ix += 1;
+                }
+                std::cmp::Ordering::Greater => {
+                    pos.y += step_y;
+                    iy += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    cells.push(Pos::new(pos.x + step_x, pos.y));
+                    cells.push(Pos::new(pos.x, pos.y + step_y));
+                    pos += Offset::new(step_x, step_y);
+                    ix += 1;
+                    iy += 1;
+                }
+            }
+            cells.push(pos);
+        }
+        cells
+    }
+
+    /// The number of positions lying exactly on both segments.
+    pub fn intersections(&self, other: &Segment) -> usize {
+        if self.line() == other.line() {
+            let (a, b) = (self.span(), other.span());
+            let (low, high) = (a.0.max(b.0), a.1.min(b.1));
+            let direction = self.line().0;
+            let spacing = dot(direction, direction);
+            return match high >= low {
+                true => ((high - low) / spacing + 1) as usize,
+                false => 0,
+            };
+        }
+        self.crossing(other).map_or(0, |_| 1)
+    }
+
+    /// Whether `pos` lies exactly on the segment.
+    pub fn contains(&self, pos: Pos) -> bool {
+        cross(self.end - self.start, pos - self.start) == 0
+            && (self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)).contains(&pos.x)
+            && (self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)).contains(&pos.y)
+    }
+
+    /// The single position where two segments on different lines cross, if
+    /// they do so exactly on a position.
+    fn crossing(&self, other: &Segment) -> Option<Pos> {
+        // A single point has no direction to cross with
+        if self.start == self.end {
+            return Some(self.start).filter(|&pos| other.contains(pos));
+        }
+        if other.start == other.end {
+            return Some(other.start).filter(|&pos| self.contains(pos));
+        }
+        let (a, b) = (self.end - self.start, other.end - other.start);
+        let between = other.start - self.start;
+        let mut denominator = cross(a, b);
+        if denominator == 0 {
+            return None;
+        }
+        // The crossing is at self.start + a * s and other.start + b * u,
+        // where s and u are these over the denominator
+        let (mut s, mut u) = (cross(between, b), cross(between, a));
+        if denominator < 0 {
+            (s, u, denominator) = (-s, -u, -denominator);
+        }
+        if !(0..=denominator).contains(&s) || !(0..=denominator).contains(&u) {
+            return None;
+        }
+        let x = self.start.x * denominator + a.dx * s;
+        let y = self.start.y * denominator + a.dy * s;
+        match x % denominator == 0 && y % denominator == 0 {
+            true => Some(Pos::new(x / denominator, y / denominator)),
+            false => None,
+        }
+    }
+
+    /// The infinite line the segment lies on, as its direction and a value
+    /// that differs between parallel lines. Single points have no line of
+    /// their own, so each is given one through it.
+    fn line(&self) -> (Offset, isize) {
+        let mut direction = match self.step() {
+            Offset { dx: 0, dy: 0 } => Offset::new(1, 0),
+            step => step,
+        };
+        if direction.dx < 0 || (direction.dx == 0 && direction.dy < 0) {
+            direction = -direction;
+        }
+        (direction, cross(direction, self.start - Pos::ORIGIN))
+    }
+
+    /// The lowest and highest positions of the ends along the line's
+    /// direction, as dot products with it.
+    fn span(&self) -> (isize, isize) {
+        let direction = self.line().0;
+        let start = dot(self.start - Pos::ORIGIN, direction);
+        let end = dot(self.end - Pos::ORIGIN, direction);
+        (start.min(end), start.max(end))
+    }
+}
+
+/// Counts the positions lying exactly on at least two of `segments`, working
+/// from the ends of the segments rather than visiting every position.
+pub fn count_overlaps(segments: &[Segment]) -> usize {
+    // Runs along each line covered more than once, as half-open spans
+    let mut by_line: HashMap<(Offset, isize), Vec<(isize, isize)>> = HashMap::new();
+    for segment in segments {
+        by_line
+            .entry(segment.line())
+            .or_default()
+            .push(segment.span());
+    }
+    let mut runs: HashMap<(Offset, isize), Vec<(isize, isize)>> = HashMap::new();
+    let mut count = 0;
+    for (line, spans) in &by_line {
+        let spacing = dot(line.0, line.0);
+        let mut events: Vec<(isize, isize)> = spans
+            .iter()
+            .flat_map(|&(low, high)| [(low, 1), (high + spacing, -1)])
+            .collect();
+        events.sort_unstable();
+        let mut covered = 0;
+        for pair in events.windows(2) {
+            covered += pair[0].1;
+            if covered >= 2 && pair[1].0 > pair[0].0 {
+                count += ((pair[1].0 - pair[0].0) / spacing) as usize;
+                runs.entry(*line).or_default().push((pair[0].0, pair[1].0));
+            }
+        }
+    }
+
+    // Single crossings, leaving out those already in a run
+    let in_run = |segment: &Segment, pos: Pos| {
+        let line = segment.line();
+        let along = dot(pos - Pos::ORIGIN, line.0);
+        runs.get(&line)
+            .is_some_and(|runs| runs.iter().any(|run| (run.0..run.1).contains(&along)))
+    };
+    let mut crossings: HashMap<Pos, bool> = HashMap::new();
+    for (i, a) in segments.iter().enumerate() {
+        for b in &segments[i + 1..] {
+            if let Some(pos) = a.crossing(b) {
+                *crossings.entry(pos).or_default() |= in_run(a, pos) || in_run(b, pos);
+            }
+        }
+    }
+    count + crossings.values().filter(|&&in_run| !in_run).count()
+}
+
+impl<T> Grid<T> {
+    /// The cells from `from` in `direction`, not including `from` itself, up
+    /// to the edge of the grid whatever its boundary.
+    pub fn ray(&self, from: Pos, direction: Direction) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let mut pos = from;
+        std::iter::from_fn(move || {
+            pos = pos.step(direction);
+            self.get(pos).map(|value| (pos, value))
+        })
+    }
+
+    /// Walks from `from` in `direction` while `predicate` holds, returning the
+    /// first cell where it fails, or `None` if the walk reaches the edge.
+    pub fn cast(
+        &self,
+        from: Pos,
+        direction: Direction,
+        mut predicate: impl FnMut(Pos, &T) -> bool,
+    ) -> Option<Pos> {
+        self.ray(from, direction)
+            .find(|&(pos, value)| !predicate(pos, value))
+            .map(|(pos, _)| pos)
+    }
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    match b {
+        0 => a.abs(),
+        _ => gcd(b, a % b),
+    }
+}
+
+fn cross(a: Offset, b: Offset) -> isize {
+    a.dx * b.dy - a.dy * b.dx
+}
+
+fn dot(a: Offset, b: Offset) -> isize {
+    a.dx * b.dx + a.dy * b.dy
+}
+
+#[cfg(test)]
+mod geom_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn segment(x1: isize, y1: isize, x2: isize, y2: isize) -> Segment {
+        Segment::new(Pos::new(x1, y1), Pos::new(x2, y2))
+    }
+
+    fn cells(cells: &[(isize, isize)]) -> Vec<Pos> {
+        cells.iter().map(|&(x, y)| Pos::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_rasterize() {
+        assert_eq!(
+            segment(0, 0, 5, 2).bresenham(),
+            cells(&[(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)])
+        );
+        assert_eq!(
+            segment(2, 2, 0, 0).bresenham(),
+            segment(2, 2, 0, 0).lattice_points().collect::<Vec<_>>()
+        );
+        assert_eq!(segment(1, 1, 1, 1).bresenham(), cells(&[(1, 1)]));
+        assert_eq!(
+            segment(0, 0, 4, 1).supercover(),
+            cells(&[(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (4, 1)])
+        );
+        assert_eq!(
+            segment(0, 0, 2, 2).supercover(),
+            cells(&[(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)])
+        );
+        assert_eq!(
+            segment(0, 0, -4, 2).lattice_points().collect::<Vec<_>>(),
+            cells(&[(0, 0), (-2, 1), (-4, 2)])
+        );
+    }
+    #[test]
+    fn test_intersections() {
+        assert_eq!(segment(0, 0, 4, 4).intersections(&segment(0, 4, 4, 0)), 1);
+        assert_eq!(segment(0, 0, 3, 3).intersections(&segment(0, 3, 3, 0)), 0);
+        assert_eq!(segment(0, 0, 6, 3).intersections(&segment(8, 4, 2, 1)), 3);
+        assert_eq!(segment(0, 0, 6, 0).intersections(&segment(0, 1, 6, 1)), 0);
+        assert_eq!(segment(2, 0, 2, 0).intersections(&segment(0, 0, 5, 0)), 1);
+        assert_eq!(segment(2, 1, 2, 1).intersections(&segment(0, 0, 4, 2)), 1);
+    }
+    #[test]
+    fn test_count_overlaps() {
+        let segments = [
+            segment(0, 9, 5, 9),
+            segment(8, 0, 0, 8),
+            segment(9, 4, 3, 4),
+            segment(2, 2, 2, 1),
+            segment(7, 0, 7, 4),
+            segment(6, 4, 2, 0),
+            segment(0, 9, 2, 9),
+            segment(3, 4, 1, 4),
+            segment(0, 0, 8, 8),
+            segment(5, 5, 8, 2),
+            segment(0, 0, 6, 3),
+            segment(4, 2, 8, 4),
+            segment(3, 3, 3, 3),
+            segment(6, 3, 6, 3),
+        ];
+        let mut seen = HashSet::new();
+        let mut overlaps = HashSet::new();
+        for pos in segments.iter().flat_map(|segment| segment.lattice_points()) {
+            if !seen.insert(pos) {
+                overlaps.insert(pos);
+            }
+        }
+        assert_eq!(count_overlaps(&segments), overlaps.len());
+        assert_eq!(count_overlaps(&segments[..10]), 12);
+    }
+    #[test]
+    fn test_cast() {
+        let trees: Grid<usize> = "30373\n25512\n65332\n33549\n35390".parse().unwrap();
+        let from = Pos::new(2, 3);
+        let shorter = |_, &height: &usize| height < 5;
+        assert_eq!(
+            trees.cast(from, Direction::Up, shorter),
+            Some(Pos::new(2, 1))
+        );
+        assert_eq!(
+            trees.cast(from, Direction::Right, shorter),
+            Some(Pos::new(4, 3))
+        );
+        assert_eq!(trees.cast(from, Direction::Down, shorter), None);
+        assert_eq!(trees.ray(from, Direction::Left).count(), 2);
+    }
+}
@@ -1,28 +1,127 @@
-use std::error::Error;
 use std::fmt;
-use std::ops::{Index, IndexMut};
-use std::slice::IterMut;
-use std::str::FromStr;
+use std::hash::{Hash, Hasher};
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut, Range};
+use std::slice::{Chunks, Iter, IterMut};
+use std::sync::Arc;
+
+mod bitgrid;
+mod fill;
+mod image;
+mod nd;
+mod parse;
+mod pattern;
+mod pos;
+mod render;
+pub mod search;
+mod sparse;
+mod transform;
+mod window;
+pub use bitgrid::BitGrid;
+pub use fill::Components;
+pub use nd::{Grid3, GridN, NeighborhoodN, PosN};
+pub use parse::{Markers, ParseGridError};
+pub use pattern::Match;
+pub use pos::{Direction, Offset, Pos};
+pub use render::{Color, GridRenderer};
+pub use sparse::SparseGrid;
+pub use transform::Symmetry;
+pub use window::Window;
 
 pub type UsizeGrid = Grid<usize>;
 
-#[derive(Clone)]
+/// Which cells count as neighbours of a cell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The four orthogonally adjacent cells.
+    VonNeumann,
+    /// All eight surrounding cells.
+    Moore,
+    /// Any stencil of offsets from the cell, which may be built at runtime.
+    Custom(Arc<[Offset]>),
+}
+
+const VON_NEUMANN: &[Offset] = &[
+    Offset::new(0, -1),
+    Offset::new(1, 0),
+    Offset::new(0, 1),
+    Offset::new(-1, 0),
+];
+
+const MOORE: &[Offset] = &[
+    Offset::new(0, -1),
+    Offset::new(1, -1),
+    Offset::new(1, 0),
+    Offset::new(1, 1),
+    Offset::new(0, 1),
+    Offset::new(-1, 1),
+    Offset::new(-1, 0),
+    Offset::new(-1, -1),
+];
+
+impl Neighborhood {
+    pub fn offsets(&self) -> &[Offset] {
+        match self {
+            Neighborhood::VonNeumann => VON_NEUMANN,
+            Neighborhood::Moore => MOORE,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// What lies beyond the edges of a grid, as seen by its neighbour queries.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Boundary<T> {
+    /// Nothing does, so cells on the edges have fewer neighbours.
+    Clip,
+    /// The grid wraps round, so the left edge neighbours the right edge.
+    Wrap,
+    /// The grid is mirrored about its edge cells, so the cell past an edge
+    /// is the one just inside it. A grid one cell wide reflects onto itself.
+    Reflect,
+    /// Every cell off the grid has this value.
+    Fixed(T),
+}
+
+/// Grids compare and hash by their cells alone, so the boundary doesn't
+/// stop two grids of the same cells from being equal.
+#[derive(Clone, Debug)]
 pub struct Grid<T> {
     elements: Vec<T>,
     width: usize,
+    boundary: Boundary<T>,
 }
 
 impl<T> Grid<T> {
+    /// A grid with a `Clip` boundary.
     pub fn new(elements: Vec<T>, width: usize) -> Grid<T> {
-        Grid { elements, width }
+        Grid {
+            elements,
+            width,
+            boundary: Boundary::Clip,
+        }
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Grid<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    pub fn set_boundary(&mut self, boundary: Boundary<T>) {
+        self.boundary = boundary;
     }
 
     pub fn width(&self) -> usize {
         self.width
     }
 
+    /// The number of rows, which is 0 for a grid with no columns.
     pub fn height(&self) -> usize {
-        self.elements.len() / self.width()
+        self.elements.len().checked_div(self.width()).unwrap_or(0)
     }
 
     pub fn size(&self) -> usize {
@@ -37,61 +136,147 @@ impl<T> Grid<T> {
         self.size() - 1
     }
 
-    pub fn neighbors(&self, index: usize) -> Vec<usize> {
-        let mut neighbors = Vec::new();
-        // Left
-        if index % self.width != 0 {
-            neighbors.push(index - 1);
-        }
-        // Right
-        if index % self.width < self.width - 1 {
-            neighbors.push(index + 1);
-        }
-        // Up
-        if index >= self.width {
-            neighbors.push(index - self.width);
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.width() as isize).contains(&pos.x) && (0..self.height() as isize).contains(&pos.y)
+    }
+
+    /// The flat index of `pos`, or `None` if it is outside the grid.
+    pub fn index_of(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.x as usize + pos.y as usize * self.width())
+        } else {
+            None
         }
-        // Down
-        if index + self.width < self.size() {
-            neighbors.push(index + self.width);
+    }
+
+    /// The position of a flat index, or `None` if it is past the last cell.
+    pub fn pos_of(&self, index: usize) -> Option<Pos> {
+        if index < self.size() {
+            Some(Pos::new(
+                (index % self.width()) as isize,
+                (index / self.width()) as isize,
+            ))
+        } else {
+            None
         }
-        neighbors
     }
 
-    pub fn neighbors_diag(&self, index: usize) -> Vec<usize> {
-        let mut neighbors = Vec::new();
-        let right = index % self.width < self.width - 1;
-        let up = index >= self.width;
-        let left = index % self.width != 0;
-        let down = index + self.width < self.size();
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width() as isize;
+        (0..self.size() as isize).map(move |i| Pos::new(i % width, i / width))
+    }
 
-        // Neighbors positive around starting at the right
-        if right {
-            neighbors.push(index + 1);
-        }
-        if right && up {
-            neighbors.push(index + 1 - self.width);
-        }
-        if up {
-            neighbors.push(index - self.width);
-        }
-        if left && up {
-            neighbors.push(index - 1 - self.width);
-        }
-        if left {
-            neighbors.push(index - 1);
-        }
-        if left && down {
-            neighbors.push(index - 1 + self.width);
+    /// The cell that `pos` stands for under the boundary: `pos` itself if it
+    /// is in the grid, the cell it wraps or reflects onto, or `None`.
+    pub fn resolve(&self, pos: Pos) -> Option<Pos> {
+        if self.contains(pos) {
+            return Some(pos);
         }
-        if down {
-            neighbors.push(index + self.width);
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        match self.boundary {
+            Boundary::Wrap => Some(Pos::new(pos.x.rem_euclid(width), pos.y.rem_euclid(height))),
+            Boundary::Reflect => Some(Pos::new(reflect(pos.x, width), reflect(pos.y, height))),
+            Boundary::Clip | Boundary::Fixed(_) => None,
         }
-        if right && down {
-            neighbors.push(index + 1 + self.width);
+    }
+
+    /// The value at `pos` under the boundary, which includes the value of a
+    /// `Fixed` boundary off the grid.
+    pub fn get_bounded(&self, pos: Pos) -> Option<&T> {
+        match (self.resolve(pos), &self.boundary) {
+            (Some(pos), _) => self.get(pos),
+            (None, Boundary::Fixed(value)) => Some(value),
+            (None, _) => None,
         }
+    }
+
+    /// The cell one step from `pos` in `direction`, if there is one under
+    /// the boundary.
+    pub fn neighbor(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.resolve(pos.step(direction))
+    }
+
+    /// The cells around `pos` in `neighborhood`, wrapping or reflecting at
+    /// the edges as the boundary says. Cells off the grid are left out, as
+    /// is `pos` itself, and each cell is given once even if several offsets
+    /// wrap or reflect onto it.
+    pub fn neighbors_of<'a>(
+        &'a self,
+        pos: Pos,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let offsets = neighborhood.offsets();
+        let folds = matches!(self.boundary, Boundary::Wrap | Boundary::Reflect);
+        offsets.iter().enumerate().filter_map(move |(i, &offset)| {
+            let neighbor = self.resolve(pos + offset)?;
+            let repeated = folds
+                && offsets[..i]
+                    .iter()
+                    .any(|&earlier| self.resolve(pos + earlier) == Some(neighbor));
+            (neighbor != pos && !repeated).then_some(neighbor)
+        })
+    }
+
+    /// Like `neighbors_of`, along with the value of each neighbour.
+    pub fn neighbor_cells<'a>(
+        &'a self,
+        pos: Pos,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = (Pos, &'a T)> + 'a {
+        self.neighbors_of(pos, neighborhood)
+            .map(move |pos| (pos, &self[pos]))
+    }
+
+    /// The value of each neighbour of `pos`, one for each of the
+    /// neighbourhood's offsets and in their order, including those given by
+    /// a `Fixed` boundary. Neighbours a `Clip` boundary cuts off are `None`.
+    /// Unlike `neighbors_of`, a cell that several offsets wrap or reflect
+    /// onto, even `pos` itself, gives a value for each of them.
+    pub fn neighbor_values<'a>(
+        &'a self,
+        pos: Pos,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Option<&'a T>> + 'a {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| self.get_bounded(pos + offset))
+    }
+
+    fn neighbor_indices<'a>(
+        &'a self,
+        index: usize,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let pos = self.pos_of(index).unwrap_or_else(|| {
+            panic!(
+                "index {} is outside the {}x{} grid",
+                index,
+                self.width(),
+                self.height()
+            )
+        });
+        self.neighbors_of(pos, neighborhood)
+            .map(move |pos| self.index_of(pos).unwrap())
+    }
+
+    /// The flat indices of the cells orthogonally adjacent to `index`.
+    pub fn neighbors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbor_indices(index, &Neighborhood::VonNeumann)
+    }
+
+    /// The flat indices of all eight cells surrounding `index`.
+    pub fn neighbors_diag(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbor_indices(index, &Neighborhood::Moore)
+    }
 
-        neighbors
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.elements[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.elements[index])
     }
 
     pub fn get_index(&self, index: usize) -> T
@@ -105,13 +290,37 @@ impl<T> Grid<T> {
     where
         T: Copy,
     {
-        let width = self.width();
-        self.elements[x + width * y]
+        self[Pos::new(x as isize, y as isize)]
     }
 
     pub fn get_mut_coord(&mut self, x: usize, y: usize) -> &mut T {
-        let width = self.width();
-        &mut self.elements[x + width * y]
+        &mut self[Pos::new(x as isize, y as isize)]
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> Iter<'_, T> {
+        self[y].iter()
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn col(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(
+            x < self.width(),
+            "column {} is outside the {}x{} grid",
+            x,
+            self.width(),
+            self.height()
+        );
+        self.elements[x..].iter().step_by(self.width())
+    }
+
+    /// The rows of the grid, top to bottom.
+    pub fn rows(&self) -> Chunks<'_, T> {
+        self.elements.chunks(self.width())
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.elements.iter()
     }
 
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
@@ -121,57 +330,244 @@ impl<T> Grid<T> {
     pub fn elements(&self) -> &Vec<T> {
         &self.elements
     }
+
+    pub fn into_elements(self) -> Vec<T> {
+        self.elements
+    }
 }
 
-impl<T: FromStr + fmt::Debug> FromStr for Grid<T>
-where
-    <T as FromStr>::Err: fmt::Debug,
-{
-    type Err = Box<dyn Error>;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Grid {
-            elements: s
-                .replace("\n", "")
-                .split("")
-                .filter(|s| !s.is_empty())
-                .map(|s| s.parse::<T>().unwrap())
-                .collect(),
-            width: s.lines().next().unwrap().len(),
-        })
+/// Mirrors `c` back into `0..len` about the edge cells, without repeating
+/// them.
+fn reflect(c: isize, len: isize) -> isize {
+    if len == 1 {
+        return 0;
+    }
+    let period = 2 * (len - 1);
+    let c = c.rem_euclid(period);
+    if c < len {
+        c
+    } else {
+        period - c
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Grid<T>) -> bool {
+        self.width == other.width && self.elements == other.elements
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.elements.hash(state);
+        self.width.hash(state);
     }
 }
 
+impl<T> Grid<T> {
+    fn row_range(&self, y: usize) -> Range<usize> {
+        assert!(
+            y < self.height(),
+            "row {} is outside the {}x{} grid",
+            y,
+            self.width(),
+            self.height()
+        );
+        y * self.width()..(y + 1) * self.width()
+    }
+}
+
+/// Indexing a grid by `y` gives row `y`, so `grid[y][x]` is the cell at
+/// `(x, y)`.
 impl<T> Index<usize> for Grid<T> {
     type Output = [T];
 
     fn index(&self, y: usize) -> &Self::Output {
-        let width = self.width();
-        let row = y % width;
-        let start = row * width;
-        &self.elements[start..start + width]
+        &self.elements[self.row_range(y)]
     }
 }
 
 impl<T> IndexMut<usize> for Grid<T> {
     fn index_mut(&mut self, y: usize) -> &mut Self::Output {
-        let width = self.width();
-        let row = y % width;
-        let start = row * width;
-        &mut self.elements[start..start + width]
+        let range = self.row_range(y);
+        &mut self.elements[range]
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{} is outside the {}x{} grid",
+                pos,
+                self.width(),
+                self.height()
+            ),
+        }
     }
 }
 
-impl<T: Copy + fmt::Display> fmt::Display for Grid<T> {
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width(), self.height());
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{} is outside the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Draws the rows one per line with no separators, trimming whitespace from
+/// either end of the whole drawing. Use `render` to keep it.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.elements
-                .chunks(self.width())
-                .fold(String::new(), |s, row| {
-                    s + &row.iter().fold(String::new(), |s, &x| s + &x.to_string()) + "\n"
-                })
-                .trim()
-        )
+        write!(f, "{}", self.render().to_string().trim())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let grid = Grid::new((0..6).collect(), 3);
+        assert_eq!(grid.pos_of(4), Some(Pos::new(1, 1)));
+        assert_eq!(grid.pos_of(6), None);
+        assert_eq!(grid.index_of(Pos::new(2, 1)), Some(5));
+        assert_eq!(grid.index_of(Pos::new(3, 0)), None);
+        assert_eq!(grid.index_of(Pos::new(0, -1)), None);
+        assert_eq!(grid[Pos::new(2, 0)], 2);
+        assert!(grid
+            .positions()
+            .enumerate()
+            .all(|(i, pos)| grid.index_of(pos) == Some(i)));
+        assert_eq!(grid.neighbor(Pos::new(2, 0), Direction::DownRight), None);
+        assert_eq!(
+            grid.neighbor(Pos::new(2, 0), Direction::DownLeft),
+            Some(Pos::new(1, 1))
+        );
+    }
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new((0..12).collect(), 4);
+        let mut corner: Vec<usize> = grid.neighbors(0).collect();
+        corner.sort();
+        assert_eq!(corner, vec![1, 4]);
+        assert_eq!(grid.neighbors(5).count(), 4);
+        assert_eq!(grid.neighbors_diag(5).count(), 8);
+        assert_eq!(grid.neighbors_diag(11).count(), 3);
+
+        let knight = [(1, 2), (2, 1), (-1, 2)].map(|(dx, dy)| Offset::new(dx, dy));
+        let knight = Neighborhood::Custom(Arc::from(knight));
+        assert_eq!(
+            grid.neighbor_cells(Pos::ORIGIN, &knight)
+                .collect::<Vec<_>>(),
+            vec![(Pos::new(1, 2), &9), (Pos::new(2, 1), &6)]
+        );
+    }
+    #[test]
+    fn test_boundary() {
+        let values = |grid: &Grid<usize>| {
+            grid.neighbor_values(Pos::ORIGIN, &Neighborhood::VonNeumann)
+                .map(|value| value.copied())
+                .collect::<Vec<_>>()
+        };
+        let grid = Grid::new((0..12).collect(), 4);
+        assert_eq!(values(&grid), vec![None, Some(1), Some(4), None]);
+        let grid = grid.with_boundary(Boundary::Wrap);
+        assert_eq!(values(&grid), vec![Some(8), Some(1), Some(4), Some(3)]);
+        assert_eq!(
+            grid.neighbor(Pos::new(3, 2), Direction::DownRight),
+            Some(Pos::ORIGIN)
+        );
+        let grid = grid.with_boundary(Boundary::Reflect);
+        assert_eq!(values(&grid), vec![Some(4), Some(1), Some(4), Some(1)]);
+        assert_eq!(grid.resolve(Pos::new(-5, 4)), Some(Pos::new(1, 0)));
+        assert_eq!(grid.resolve(Pos::new(4, -1)), Some(Pos::new(2, 1)));
+        let column = Grid::new(vec![7], 1).with_boundary(Boundary::Reflect);
+        assert_eq!(column.resolve(Pos::new(-3, 2)), Some(Pos::ORIGIN));
+        let grid = grid.with_boundary(Boundary::Fixed(99));
+        assert_eq!(values(&grid), vec![Some(99), Some(1), Some(4), Some(99)]);
+        assert_eq!(
+            grid.neighbors_of(Pos::ORIGIN, &Neighborhood::Moore).count(),
+            3
+        );
+        assert_eq!(grid.get(Pos::new(-1, 0)), None);
+    }
+    #[test]
+    fn test_folded_neighbors() {
+        // In a wrapped column, left and right lead back to the cell itself
+        let column = Grid::new(vec![0; 3], 1).with_boundary(Boundary::Wrap);
+        let neighbors = |grid: &Grid<usize>, pos| {
+            grid.neighbors_of(pos, &Neighborhood::Moore)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            neighbors(&column, Pos::new(0, 1)),
+            vec![Pos::new(0, 0), Pos::new(0, 2)]
+        );
+        // Up and down wrap onto the same cell of a two row grid
+        let pair = Grid::new(vec![0; 2], 1).with_boundary(Boundary::Wrap);
+        assert_eq!(neighbors(&pair, Pos::ORIGIN), vec![Pos::new(0, 1)]);
+        let single = Grid::new(vec![0], 1).with_boundary(Boundary::Reflect);
+        assert!(neighbors(&single, Pos::ORIGIN).is_empty());
+        // neighbor_values still has a value for every offset
+        assert_eq!(
+            column
+                .neighbor_values(Pos::new(0, 1), &Neighborhood::VonNeumann)
+                .count(),
+            4
+        );
+    }
+    #[test]
+    fn test_equality() {
+        use std::collections::HashSet;
+        let clipped = Grid::new((0..6).collect::<Vec<usize>>(), 3);
+        let wrapped = clipped.clone().with_boundary(Boundary::Wrap);
+        assert_eq!(clipped, wrapped);
+        assert_ne!(clipped, Grid::new((0..6).collect(), 2));
+        let seen: HashSet<Grid<usize>> = [clipped, wrapped].into_iter().collect();
+        assert_eq!(seen.len(), 1);
+    }
+    #[test]
+    fn test_display() {
+        let grid = Grid::new(vec![1, 2, 3, 4], 2);
+        assert_eq!(grid.to_string(), "12\n34");
+        let grid = Grid::new(vec![' ', '#', '#', ' '], 2);
+        assert_eq!(grid.to_string(), "#\n#");
+        assert_eq!(grid.render().to_string(), " #\n# ");
+    }
+    #[test]
+    fn test_rows_and_cols() {
+        // 3 wide, 2 tall: row 1 used to wrap back round to row 1 % 3
+        let mut grid = Grid::new((0..6).collect(), 3);
+        assert_eq!(grid[1], [3, 4, 5]);
+        assert_eq!(grid.row(0).copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        grid[1][0] = 9;
+        assert_eq!(grid.get_coord(0, 1), 9);
+    }
+    #[test]
+    #[should_panic(expected = "row 2 is outside the 3x2 grid")]
+    fn test_row_out_of_bounds() {
+        let grid = Grid::new((0..6).collect(), 3);
+        let _ = &grid[2];
+    }
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::new(["a", "b", "c", "d"].map(String::from).to_vec(), 2);
+        assert_eq!(grid.get(Pos::new(1, 1)).map(String::as_str), Some("d"));
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+        grid.get_mut(Pos::new(0, 1)).unwrap().push('!');
+        assert_eq!(grid[Pos::new(0, 1)], "c!");
+        assert!(grid.get_mut(Pos::new(0, 2)).is_none());
     }
 }
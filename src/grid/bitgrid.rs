@@ -0,0 +1,359 @@
+use super::{Grid, Offset, Pos};
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans packed 64 to a word, so that whole grids can be
+/// combined and shifted a word at a time. Each row starts on a new word, with
+/// cell `x` of a row in bit `x % 64` of its word `x / 64`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl BitGrid {
+    /// A grid with every cell unset.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        BitGrid {
+            words: vec![0; width.div_ceil(BITS) * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.width as isize).contains(&pos.x) && (0..self.height as isize).contains(&pos.y)
+    }
+
+    /// The number of words in each row.
+    fn stride(&self) -> usize {
+        self.width.div_ceil(BITS)
+    }
+
+    /// The index of the word holding `pos` and the mask of its bit.
+    fn locate(&self, pos: Pos) -> Option<(usize, u64)> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        Some((y * self.stride() + x / BITS, 1 << (x % BITS)))
+    }
+
+    /// Clears the padding bits past the end of each row, which every
+    /// operation that can set them must call.
+    fn mask_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+        let stride = self.stride();
+        for row in self.words.chunks_mut(stride) {
+            row[stride - 1] &= (1 << used) - 1;
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<bool> {
+        self.locate(pos)
+            .map(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    pub fn set(&mut self, pos: Pos, value: bool) {
+        let (word, mask) = self.locate(pos).unwrap_or_else(|| {
+            panic!("{} is outside the {}x{} grid", pos, self.width, self.height)
+        });
+        match value {
+            true => self.words[word] |= mask,
+            false => self.words[word] &= !mask,
+        }
+    }
+
+    /// The number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width as isize;
+        (0..self.size() as isize).map(move |i| Pos::new(i % width, i / width))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.positions().map(|pos| self.get(pos).unwrap())
+    }
+
+    /// The positions of the set cells, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = Pos> + '_ {
+        let stride = self.stride();
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let y = (index / stride) as isize;
+                let x = (index % stride * BITS) as isize;
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    if rest == 0 {
+                        return None;
+                    }
+                    let bit = rest.trailing_zeros() as isize;
+                    rest &= rest - 1;
+                    Some(Pos::new(x + bit, y))
+                })
+            })
+    }
+
+    /// A copy with every cell moved by `offset`. Cells moved off the grid are
+    /// lost and cells moved from off the grid are unset.
+    pub fn shift(&self, offset: Offset) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        let stride = self.stride();
+        for y in 0..self.height as isize {
+            let from = y - offset.dy;
+            if !(0..self.height as isize).contains(&from) {
+                continue;
+            }
+            let from = from as usize * stride;
+            let to = y as usize * stride;
+            shift_row(
+                &self.words[from..from + stride],
+                &mut shifted.words[to..to + stride],
+                offset.dx,
+            );
+        }
+        shifted.mask_padding();
+        shifted
+    }
+
+    /// A copy with a border of `by` unset cells on every side.
+    pub fn pad(&self, by: usize) -> BitGrid {
+        let mut padded = BitGrid::new(self.width + 2 * by, self.height + 2 * by);
+        let (stride, padded_stride) = (self.stride(), padded.stride());
+        let mut row = vec![0; padded_stride];
+        for y in 0..self.height {
+            row[..stride].copy_from_slice(&self.words[y * stride..(y + 1) * stride]);
+            let to = (y + by) * padded_stride;
+            shift_row(&row, &mut padded.words[to..to + padded_stride], by as isize);
+        }
+        padded
+    }
+
+    /// A `Grid` of the cells, for rendering or other `Grid` operations.
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::new(self.iter().collect(), self.width)
+    }
+}
+
+/// Moves the cells of the row `from` by `dx` into `to`.
+fn shift_row(from: &[u64], to: &mut [u64], dx: isize) {
+    let words = dx.unsigned_abs() / BITS;
+    let bits = dx.unsigned_abs() % BITS;
+    for (i, word) in to.iter_mut().enumerate() {
+        *word = if dx >= 0 {
+            let low = i.checked_sub(words).map_or(0, |j| from[j] << bits);
+            let carry = match bits {
+                0 => 0,
+                _ => i
+                    .checked_sub(words + 1)
+                    .map_or(0, |j| from[j] >> (BITS - bits)),
+            };
+            low | carry
+        } else {
+            let high = from.get(i + words).map_or(0, |word| word >> bits);
+            let carry = match bits {
+                0 => 0,
+                _ => from
+                    .get(i + words + 1)
+                    .map_or(0, |word| word << (BITS - bits)),
+            };
+            high | carry
+        };
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> BitGrid {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        grid.positions()
+            .zip(grid.iter())
+            .filter(|(_, &value)| value)
+            .for_each(|(pos, _)| bits.set(pos, true));
+        bits
+    }
+}
+
+/// Implements a bitwise operator cell by cell between grids of the same size.
+macro_rules! bitwise {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $assign_trait<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                assert_eq!(
+                    (self.width, self.height),
+                    (other.width, other.height),
+                    "grids must be the same size"
+                );
+                self.words
+                    .iter_mut()
+                    .zip(&other.words)
+                    .for_each(|(word, other)| word.$assign_method(other));
+            }
+        }
+
+        impl $trait<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+    fn not(self) -> BitGrid {
+        let mut result = BitGrid {
+            words: self.words.iter().map(|word| !word).collect(),
+            width: self.width,
+            height: self.height,
+        };
+        result.mask_padding();
+        result
+    }
+}
+
+/// Draws set cells as `#` and unset cells as `.`.
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let grid = self.to_grid();
+        let rendered = grid.render_with(|_, &set| String::from(if set { "#" } else { "." }));
+        write!(f, "{}", rendered)
+    }
+}
+
+#[cfg(test)]
+mod bitgrid_tests {
+    use super::*;
+
+    fn bits(text: &str) -> BitGrid {
+        BitGrid::from(&Grid::parse_with(text, |c| Ok::<bool, &str>(c == '#')).unwrap())
+    }
+
+    /// A step of Conway's game of life, counting neighbours for every cell at
+    /// once in three bit planes.
+    fn life(grid: &BitGrid) -> BitGrid {
+        let mut count = [
+            BitGrid::new(grid.width(), grid.height()),
+            BitGrid::new(grid.width(), grid.height()),
+            BitGrid::new(grid.width(), grid.height()),
+        ];
+        for offset in crate::grid::Neighborhood::Moore.offsets() {
+            let mut carry = grid.shift(*offset);
+            for plane in count.iter_mut() {
+                let next = &*plane & &carry;
+                *plane ^= &carry;
+                carry = next;
+            }
+        }
+        let [ones, twos, fours] = count;
+        // Two or three neighbours, and alive already if only two
+        &(&twos & &!&fours) & &(&ones | grid)
+    }
+
+    #[test]
+    fn test_cells() {
+        let mut grid = BitGrid::new(100, 3);
+        grid.set(Pos::new(70, 1), true);
+        grid.set(Pos::new(3, 2), true);
+        assert_eq!(grid.get(Pos::new(70, 1)), Some(true));
+        assert_eq!(grid.get(Pos::new(69, 1)), Some(false));
+        assert_eq!(grid.get(Pos::new(100, 1)), None);
+        assert_eq!(grid.count_ones(), 2);
+        assert_eq!(
+            grid.ones().collect::<Vec<_>>(),
+            vec![Pos::new(70, 1), Pos::new(3, 2)]
+        );
+        grid.set(Pos::new(70, 1), false);
+        assert_eq!(grid.count_ones(), 1);
+        assert_eq!((!&grid).count_ones(), 299);
+    }
+    #[test]
+    fn test_shift() {
+        let grid = bits("#..#\n.#..");
+        assert_eq!(grid.shift(Offset::new(1, 0)).to_string(), ".#..\n..#.");
+        assert_eq!(grid.shift(Offset::new(-1, 1)).to_string(), "....\n..#.");
+
+        let mut wide = BitGrid::new(130, 1);
+        wide.set(Pos::new(63, 0), true);
+        wide.set(Pos::new(127, 0), true);
+        let shifted = wide.shift(Offset::new(65, 0));
+        assert_eq!(shifted.ones().collect::<Vec<_>>(), vec![Pos::new(128, 0)]);
+        let shifted = shifted.shift(Offset::new(-128, 0));
+        assert_eq!(shifted.ones().collect::<Vec<_>>(), vec![Pos::new(0, 0)]);
+    }
+    #[test]
+    fn test_pad() {
+        let grid = bits("#.\n.#");
+        assert_eq!(grid.pad(1).to_string(), "....\n.#..\n..#.\n....");
+
+        let mut wide = BitGrid::new(64, 1);
+        wide.set(Pos::new(0, 0), true);
+        wide.set(Pos::new(63, 0), true);
+        let padded = wide.pad(2);
+        assert_eq!((padded.width(), padded.height()), (68, 5));
+        assert_eq!(
+            padded.ones().collect::<Vec<_>>(),
+            vec![Pos::new(2, 2), Pos::new(65, 2)]
+        );
+    }
+    #[test]
+    fn test_bitwise() {
+        let a = bits("##..\n.#.#");
+        let b = bits("#.#.\n.##.");
+        assert_eq!((&a & &b).to_string(), "#...\n.#..");
+        assert_eq!((&a | &b).to_string(), "###.\n.###");
+        assert_eq!((&a ^ &b).to_string(), ".##.\n..##");
+        assert_eq!((!&a).to_string(), "..##\n#.#.");
+        assert_eq!(
+            a.to_grid().elements(),
+            &vec![true, true, false, false, false, true, false, true]
+        );
+    }
+    #[test]
+    fn test_life() {
+        // A glider crossing the boundary between words
+        let mut grid = BitGrid::new(70, 8);
+        for (x, y) in [(62, 1), (63, 2), (61, 3), (62, 3), (63, 3)] {
+            grid.set(Pos::new(x, y), true);
+        }
+        let mut glider = grid.clone();
+        for _ in 0..4 {
+            glider = life(&glider);
+        }
+        assert_eq!(glider, grid.shift(Offset::new(1, 1)));
+    }
+}
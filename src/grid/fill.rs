@@ -0,0 +1,184 @@
+use super::{Grid, Neighborhood, Pos};
+use std::collections::VecDeque;
+
+/// Connected regions of a grid, numbered in the order their first cell comes
+/// in reading order.
+#[derive(Clone, Debug)]
+pub struct Components {
+    /// The component of each cell, or `None` for cells in no component.
+    pub labels: Grid<Option<usize>>,
+    /// The number of cells in each component.
+    pub sizes: Vec<usize>,
+    /// The top left and bottom right corners of each component.
+    pub bounds: Vec<(Pos, Pos)>,
+}
+
+impl Components {
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    /// The component containing `pos`, if any.
+    pub fn label(&self, pos: Pos) -> Option<usize> {
+        self.labels.get(pos).copied().flatten()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every cell reachable from `start` through cells matching `predicate`,
+    /// in the order they were reached. Empty if `start` itself doesn't match.
+    pub fn flood_fill(
+        &self,
+        start: Pos,
+        neighborhood: &Neighborhood,
+        mut predicate: impl FnMut(Pos, &T) -> bool,
+    ) -> Vec<Pos> {
+        let mut filled = Vec::new();
+        match self.get(start) {
+            Some(value) if predicate(start, value) => filled.push(start),
+            _ => return filled,
+        }
+        let mut seen = vec![false; self.size()];
+        seen[self.index_of(start).unwrap()] = true;
+        let mut next = 0;
+        while let Some(&pos) = filled.get(next) {
+            next += 1;
+            for (neighbor, value) in self.neighbor_cells(pos, neighborhood) {
+                let index = self.index_of(neighbor).unwrap();
+                if !seen[index] && predicate(neighbor, value) {
+                    seen[index] = true;
+                    filled.push(neighbor);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Labels the connected regions of cells matching `predicate`.
+    pub fn components(
+        &self,
+        neighborhood: &Neighborhood,
+        mut predicate: impl FnMut(Pos, &T) -> bool,
+    ) -> Components {
+        let included = self
+            .positions()
+            .zip(self.iter())
+            .map(|(pos, value)| predicate(pos, value))
+            .collect();
+        self.label(neighborhood, included, |_, _| true)
+    }
+
+    /// Labels every cell, with neighbouring cells in the same component when
+    /// `same` holds for their values, e.g. regions of equal cells.
+    pub fn components_by(
+        &self,
+        neighborhood: &Neighborhood,
+        mut same: impl FnMut(&T, &T) -> bool,
+    ) -> Components {
+        self.label(neighborhood, vec![true; self.size()], |a, b| {
+            same(&self.elements[a], &self.elements[b])
+        })
+    }
+
+    /// Labels the connected regions of `included` cells, where neighbouring
+    /// cells (by flat index) are joined if `connected` holds.
+    fn label(
+        &self,
+        neighborhood: &Neighborhood,
+        included: Vec<bool>,
+        mut connected: impl FnMut(usize, usize) -> bool,
+    ) -> Components {
+        let mut labels = vec![None; self.size()];
+        let mut sizes = Vec::new();
+        let mut bounds = Vec::new();
+        for start in 0..self.size() {
+            if !included[start] || labels[start].is_some() {
+                continue;
+            }
+            let label = sizes.len();
+            let first = self.pos_of(start).unwrap();
+            let (mut min, mut max) = (first, first);
+            let mut size = 0;
+            let mut queue = VecDeque::from([start]);
+            labels[start] = Some(label);
+            while let Some(index) = queue.pop_front() {
+                let pos = self.pos_of(index).unwrap();
+                size += 1;
+                min = Pos::new(min.x.min(pos.x), min.y.min(pos.y));
+                max = Pos::new(max.x.max(pos.x), max.y.max(pos.y));
+                for neighbor in self.neighbors_of(pos, neighborhood) {
+                    let neighbor = self.index_of(neighbor).unwrap();
+                    if included[neighbor]
+                        && labels[neighbor].is_none()
+                        && connected(index, neighbor)
+                    {
+                        labels[neighbor] = Some(label);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            sizes.push(size);
+            bounds.push((min, max));
+        }
+        Components {
+            labels: Grid::new(labels, self.width()),
+            sizes,
+            bounds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod fill_tests {
+    use super::*;
+
+    const MAP: &str = "AAB
+ABB
+CCB
+AAC";
+
+    fn map() -> Grid<char> {
+        Grid::parse_with(MAP, Ok::<char, &str>).unwrap()
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = map();
+        let filled = grid.flood_fill(Pos::new(2, 0), &Neighborhood::VonNeumann, |_, &c| c == 'B');
+        assert_eq!(filled.len(), 4);
+        assert_eq!(filled[0], Pos::new(2, 0));
+        assert!(grid
+            .flood_fill(Pos::new(0, 2), &Neighborhood::VonNeumann, |_, &c| c == 'A')
+            .is_empty());
+        assert_eq!(
+            grid.flood_fill(Pos::new(2, 3), &Neighborhood::Moore, |_, &c| c != 'A')
+                .len(),
+            7
+        );
+    }
+    #[test]
+    fn test_components() {
+        let grid = map();
+        let components = grid.components(&Neighborhood::VonNeumann, |_, &c| c == 'A');
+        assert_eq!(components.sizes, vec![3, 2]);
+        assert_eq!(
+            components.bounds,
+            vec![
+                (Pos::new(0, 0), Pos::new(1, 1)),
+                (Pos::new(0, 3), Pos::new(1, 3))
+            ]
+        );
+        assert_eq!(components.label(Pos::new(1, 3)), Some(1));
+        assert_eq!(components.label(Pos::new(2, 2)), None);
+    }
+    #[test]
+    fn test_components_by() {
+        let components = map().components_by(&Neighborhood::VonNeumann, |a, b| a == b);
+        assert_eq!(components.sizes, vec![3, 4, 2, 2, 1]);
+        assert_eq!(components.label(Pos::new(2, 3)), Some(4));
+    }
+}
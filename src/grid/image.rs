@@ -0,0 +1,134 @@
+//! Saving grids as images, one pixel per cell, with a palette mapping each
+//! cell to its colour.
+
+use super::Grid;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// The most data a stored (uncompressed) deflate block can hold.
+const MAX_STORED_BLOCK: usize = 0xffff;
+
+impl<T> Grid<T> {
+    /// A binary PPM image.
+    pub fn to_ppm(&self, mut palette: impl FnMut(&T) -> [u8; 3]) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        image.extend(self.iter().flat_map(&mut palette));
+        image
+    }
+
+    /// A PNG image. The pixel data is stored uncompressed, so the file is
+    /// about as big as a PPM.
+    pub fn to_png(&self, mut palette: impl FnMut(&T) -> [u8; 3]) -> Vec<u8> {
+        let row_bytes = self.width() * 3;
+        let mut scanlines = Vec::with_capacity((row_bytes + 1) * self.height());
+        for row in self.rows() {
+            // Each scanline starts with its filter type, which is none
+            scanlines.push(0);
+            scanlines.extend(row.iter().flat_map(&mut palette));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, RGB, and the only compression, filter and
+        // interlace methods
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut image = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut image, b"IHDR", &header);
+        write_chunk(&mut image, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut image, b"IEND", &[]);
+        image
+    }
+}
+
+fn write_chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    let start = image.len();
+    image.extend(kind);
+    image.extend(data);
+    let crc = crc32(&image[start..]);
+    image.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len() / MAX_STORED_BLOCK + 1;
+    let mut stream = Vec::with_capacity(data.len() + 5 * blocks + 6);
+    // Deflate with a 32K window and no preset dictionary
+    stream.extend([0x78, 0x01]);
+    let mut chunks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(chunk) = chunks.next() {
+        let last = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        stream.push(last as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(chunk);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xedb8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod image_tests {
+    use super::*;
+
+    fn palette(&lit: &bool) -> [u8; 3] {
+        match lit {
+            true => [255, 255, 255],
+            false => [0, 0, 0],
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::new(vec![true, false], 2);
+        assert_eq!(
+            grid.to_ppm(palette),
+            b"P6\n2 1\n255\n\xff\xff\xff\x00\x00\x00".to_vec()
+        );
+    }
+    #[test]
+    fn test_png() {
+        let grid = Grid::new(vec![true, false, false, true], 2);
+        let png = grid.to_png(palette);
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(png[16..24], [0, 0, 0, 2, 0, 0, 0, 2]);
+        // Two scanlines of a filter byte and two pixels, in a single block
+        let idat = &png[33..];
+        assert_eq!(idat[..8], [0, 0, 0, 25, b'I', b'D', b'A', b'T']);
+        assert_eq!(idat[8..15], [0x78, 0x01, 1, 14, 0, !14, 0xff]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+
+        let big = Grid::new(vec![false; 200 * 200], 200).to_png(palette);
+        let data = 200 * (200 * 3 + 1);
+        assert_eq!(big.len(), 33 + 12 + 2 + 5 * 2 + data + 4 + 12);
+    }
+}
@@ -0,0 +1,461 @@
+use super::{Grid, ParseGridError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A position in a `GridN`, with the x coordinate first.
+pub type PosN<const D: usize> = [isize; D];
+
+pub type Grid3<T> = GridN<T, 3>;
+
+/// Which cells count as neighbours of a cell in a `GridN`. The offsets are
+/// built once, when the neighbourhood is made, so that it can be reused for
+/// every cell of a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborhoodN<const D: usize> {
+    offsets: Arc<[PosN<D>]>,
+}
+
+impl<const D: usize> NeighborhoodN<D> {
+    /// The cells differing in one coordinate, 6 in 3-D.
+    pub fn von_neumann() -> NeighborhoodN<D> {
+        NeighborhoodN::custom(standard_offsets(false))
+    }
+
+    /// The cells differing by at most one in any coordinates, 26 in 3-D.
+    pub fn moore() -> NeighborhoodN<D> {
+        NeighborhoodN::custom(standard_offsets(true))
+    }
+
+    /// Any stencil of offsets from the cell.
+    pub fn custom(offsets: impl Into<Arc<[PosN<D>]>>) -> NeighborhoodN<D> {
+        NeighborhoodN {
+            offsets: offsets.into(),
+        }
+    }
+
+    pub fn offsets(&self) -> &[PosN<D>] {
+        &self.offsets
+    }
+}
+
+/// A dense grid in any number of dimensions. The elements are stored with
+/// the first coordinate varying fastest, so a `GridN<T, 2>` is laid out like
+/// a `Grid<T>` and a `Grid3<T>` is a stack of 2-D layers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GridN<T, const D: usize> {
+    elements: Vec<T>,
+    dims: [usize; D],
+}
+
+impl<T, const D: usize> GridN<T, D> {
+    pub fn new(elements: Vec<T>, dims: [usize; D]) -> GridN<T, D> {
+        assert_eq!(
+            elements.len(),
+            dims.iter().product::<usize>(),
+            "elements don't fill a {:?} grid",
+            dims
+        );
+        GridN { elements, dims }
+    }
+
+    pub fn filled(value: T, dims: [usize; D]) -> GridN<T, D>
+    where
+        T: Clone,
+    {
+        GridN::new(vec![value; dims.iter().product()], dims)
+    }
+
+    /// A grid just big enough to hold `points` with `padding` cells of
+    /// background around them, where the points have `value`. Returns the
+    /// grid along with the position its origin corresponds to, or `None` if
+    /// there are no points.
+    pub fn from_points(
+        points: impl IntoIterator<Item = PosN<D>>,
+        value: T,
+        background: T,
+        padding: usize,
+    ) -> Option<(GridN<T, D>, PosN<D>)>
+    where
+        T: Clone,
+    {
+        let points: Vec<PosN<D>> = points.into_iter().collect();
+        let (mut min, mut max) = points.iter().fold(None, |bounds, &point| {
+            Some(match bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    combine(min, point, isize::min),
+                    combine(max, point, isize::max),
+                ),
+            })
+        })?;
+        min.iter_mut().for_each(|c| *c -= padding as isize);
+        max.iter_mut().for_each(|c| *c += padding as isize);
+
+        let dims = combine(max, min, |max, min| max - min + 1).map(|dim| dim as usize);
+        let mut grid = GridN::filled(background, dims);
+        for point in points {
+            grid[combine(point, min, |c, min| c - min)] = value.clone();
+        }
+        Some((grid, min))
+    }
+
+    pub fn dims(&self) -> [usize; D] {
+        self.dims
+    }
+
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn contains(&self, pos: PosN<D>) -> bool {
+        pos.iter()
+            .zip(self.dims)
+            .all(|(&c, dim)| c >= 0 && (c as usize) < dim)
+    }
+
+    pub fn index_of(&self, pos: PosN<D>) -> Option<usize> {
+        if !self.contains(pos) {
+            return None;
+        }
+        Some(
+            pos.iter()
+                .zip(self.dims)
+                .rev()
+                .fold(0, |index, (&c, dim)| index * dim + c as usize),
+        )
+    }
+
+    pub fn pos_of(&self, mut index: usize) -> Option<PosN<D>> {
+        if index >= self.size() {
+            return None;
+        }
+        let mut pos = [0; D];
+        for (c, dim) in pos.iter_mut().zip(self.dims) {
+            *c = (index % dim) as isize;
+            index /= dim;
+        }
+        Some(pos)
+    }
+
+    /// Every position in the grid, in the order the elements are stored.
+    pub fn positions(&self) -> impl Iterator<Item = PosN<D>> + '_ {
+        (0..self.size()).map(|index| self.pos_of(index).unwrap())
+    }
+
+    pub fn get(&self, pos: PosN<D>) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.elements[index])
+    }
+
+    pub fn get_mut(&mut self, pos: PosN<D>) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.elements[index])
+    }
+
+    /// The positions next to `pos` that are inside the grid.
+    pub fn neighbors_of<'a>(
+        &'a self,
+        pos: PosN<D>,
+        neighborhood: &'a NeighborhoodN<D>,
+    ) -> impl Iterator<Item = PosN<D>> + 'a {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| combine(pos, offset, |c, d| c + d))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// The lowest and highest corners of the smallest box holding every cell
+    /// matching `predicate`, or `None` if none match.
+    pub fn bounds(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(PosN<D>, PosN<D>)> {
+        self.positions()
+            .zip(self.iter())
+            .filter(|(_, value)| predicate(value))
+            .fold(None, |bounds, (pos, _)| match bounds {
+                None => Some((pos, pos)),
+                Some((min, max)) => {
+                    Some((combine(min, pos, isize::min), combine(max, pos, isize::max)))
+                }
+            })
+    }
+
+    /// Every cell reachable from `start` through cells matching `predicate`,
+    /// in the order they were reached, like `Grid::flood_fill`.
+    pub fn flood_fill(
+        &self,
+        start: PosN<D>,
+        neighborhood: &NeighborhoodN<D>,
+        mut predicate: impl FnMut(PosN<D>, &T) -> bool,
+    ) -> Vec<PosN<D>> {
+        let mut filled = Vec::new();
+        match self.get(start) {
+            Some(value) if predicate(start, value) => filled.push(start),
+            _ => return filled,
+        }
+        let offsets = neighborhood.offsets();
+        let mut seen = vec![false; self.size()];
+        seen[self.index_of(start).unwrap()] = true;
+        let mut next = 0;
+        while let Some(&pos) = filled.get(next) {
+            next += 1;
+            for &offset in offsets.iter() {
+                let neighbor = combine(pos, offset, |c, d| c + d);
+                let index = match self.index_of(neighbor) {
+                    Some(index) => index,
+                    None => continue,
+                };
+                if !seen[index] && predicate(neighbor, &self.elements[index]) {
+                    seen[index] = true;
+                    filled.push(neighbor);
+                }
+            }
+        }
+        filled
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.elements.iter_mut()
+    }
+
+    pub fn elements(&self) -> &Vec<T> {
+        &self.elements
+    }
+
+    pub fn into_elements(self) -> Vec<T> {
+        self.elements
+    }
+}
+
+impl<T> Grid3<T> {
+    /// The 2-D slice of the grid at depth `z`.
+    pub fn layer(&self, z: usize) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let [width, height, depth] = self.dims;
+        if z >= depth {
+            return None;
+        }
+        let area = width * height;
+        Some(Grid::new(
+            self.elements[z * area..(z + 1) * area].to_vec(),
+            width,
+        ))
+    }
+
+    /// Parses layers of text separated by blank lines, the first layer
+    /// being `z = 0`, with each layer parsed like `Grid::parse_with`.
+    pub fn parse_with<E: fmt::Display>(
+        text: &str,
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid3<T>, ParseGridError> {
+        let text = text.trim_end_matches(['\n', '\r']);
+        let mut layers = vec![String::new()];
+        for line in text.lines() {
+            // Any run of blank lines separates one pair of layers
+            if line.is_empty() {
+                if !layers.last().unwrap().is_empty() {
+                    layers.push(String::new());
+                }
+            } else {
+                let layer = layers.last_mut().unwrap();
+                layer.push_str(line);
+                layer.push('\n');
+            }
+        }
+
+        let mut elements = Vec::new();
+        let mut expected = None;
+        for (layer, text) in layers.iter().enumerate() {
+            let grid =
+                Grid::parse_with(text, &mut parse).map_err(|error| ParseGridError::Layer {
+                    layer,
+                    error: Box::new(error),
+                })?;
+            let size = (grid.width(), grid.height());
+            if *expected.get_or_insert(size) != size {
+                return Err(ParseGridError::Mismatched {
+                    layer,
+                    size,
+                    expected: expected.unwrap(),
+                });
+            }
+            elements.extend(grid.into_elements());
+        }
+        let (width, height) = expected.unwrap();
+        Ok(GridN::new(elements, [width, height, layers.len()]))
+    }
+}
+
+/// Parses layers of one char per cell, each parsed with `T: FromStr`.
+impl<T: FromStr> FromStr for Grid3<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseGridError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid3::parse_with(s, |c| c.to_string().parse())
+    }
+}
+
+impl<T, const D: usize> Index<PosN<D>> for GridN<T, D> {
+    type Output = T;
+    fn index(&self, pos: PosN<D>) -> &T {
+        match self.index_of(pos) {
+            Some(index) => &self.elements[index],
+            None => panic!("{:?} is outside the {:?} grid", pos, self.dims),
+        }
+    }
+}
+
+impl<T, const D: usize> IndexMut<PosN<D>> for GridN<T, D> {
+    fn index_mut(&mut self, pos: PosN<D>) -> &mut T {
+        match self.index_of(pos) {
+            Some(index) => &mut self.elements[index],
+            None => panic!("{:?} is outside the {:?} grid", pos, self.dims),
+        }
+    }
+}
+
+fn combine<const D: usize>(a: PosN<D>, b: PosN<D>, f: impl Fn(isize, isize) -> isize) -> PosN<D> {
+    let mut combined = a;
+    combined.iter_mut().zip(b).for_each(|(c, b)| *c = f(*c, b));
+    combined
+}
+
+/// The offsets to every cell differing from the origin by at most one in
+/// each coordinate: in one coordinate only, or in any with `moore`.
+fn standard_offsets<const D: usize>(moore: bool) -> Vec<PosN<D>> {
+    let cube = (0..3usize.pow(D as u32)).map(|mut n| {
+        let mut offset = [0; D];
+        for c in offset.iter_mut() {
+            *c = (n % 3) as isize - 1;
+            n /= 3;
+        }
+        offset
+    });
+    let nonzero = |offset: &PosN<D>| offset.iter().filter(|&&c| c != 0).count();
+    match moore {
+        false => cube.filter(|offset| nonzero(offset) == 1).collect(),
+        true => cube.filter(|offset| nonzero(offset) > 0).collect(),
+    }
+}
+
+#[cfg(test)]
+mod nd_tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let grid = GridN::new((0..24).collect(), [2, 3, 4]);
+        assert_eq!(grid.index_of([1, 2, 3]), Some(23));
+        assert_eq!(grid.index_of([1, 0, 1]), Some(7));
+        assert_eq!(grid.index_of([2, 0, 0]), None);
+        assert_eq!(grid.pos_of(7), Some([1, 0, 1]));
+        assert_eq!(grid.pos_of(24), None);
+        assert_eq!(grid[[0, 1, 2]], 14);
+        assert!(grid.positions().enumerate().all(|(i, pos)| grid[pos] == i));
+    }
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid3::filled(0, [3, 3, 3]);
+        assert_eq!(
+            grid.neighbors_of([1, 1, 1], &NeighborhoodN::von_neumann())
+                .count(),
+            6
+        );
+        assert_eq!(
+            grid.neighbors_of([1, 1, 1], &NeighborhoodN::moore())
+                .count(),
+            26
+        );
+        assert_eq!(
+            grid.neighbors_of([0, 0, 0], &NeighborhoodN::moore())
+                .count(),
+            7
+        );
+        let grid = GridN::filled(0, [3, 3, 3, 3]);
+        assert_eq!(
+            grid.neighbors_of([1; 4], &NeighborhoodN::moore()).count(),
+            80
+        );
+        // A stencil of the cells above and below in 3-D
+        let vertical = NeighborhoodN::custom([[0, 0, -1], [0, 0, 1]]);
+        let grid = Grid3::filled(0, [3, 3, 3]);
+        assert_eq!(
+            grid.neighbors_of([1, 1, 0], &vertical).collect::<Vec<_>>(),
+            vec![[1, 1, 1]]
+        );
+    }
+    #[test]
+    fn test_parse() {
+        let grid: Grid3<usize> = "12\n34\n\n56\n78\n".parse().unwrap();
+        assert_eq!(grid.dims(), [2, 2, 2]);
+        assert_eq!(grid[[0, 1, 1]], 7);
+        assert_eq!(grid.layer(1).unwrap()[0], [5, 6]);
+        assert!(grid.layer(2).is_none());
+        let spaced: Grid3<usize> = "12\n34\n\n\n\n56\n78\n\n".parse().unwrap();
+        assert_eq!(spaced, grid);
+        assert_eq!(
+            "12\n34\n\n5x\n78".parse::<Grid3<usize>>().err(),
+            Some(ParseGridError::Layer {
+                layer: 1,
+                error: Box::new(ParseGridError::Cell {
+                    row: 0,
+                    col: 1,
+                    cell: String::from("x"),
+                    message: String::from("invalid digit found in string"),
+                }),
+            })
+        );
+        assert_eq!(
+            "12\n34\n\n567\n890"
+                .parse::<Grid3<usize>>()
+                .err()
+                .unwrap()
+                .to_string(),
+            "Layer 1 is 3x2 but the first layer is 2x2"
+        );
+    }
+    #[test]
+    fn test_surface_area() {
+        let droplet = [
+            [2, 2, 2],
+            [1, 2, 2],
+            [3, 2, 2],
+            [2, 1, 2],
+            [2, 3, 2],
+            [2, 2, 1],
+            [2, 2, 3],
+            [2, 2, 4],
+            [2, 2, 6],
+            [1, 2, 5],
+            [3, 2, 5],
+            [2, 1, 5],
+            [2, 3, 5],
+        ];
+        let (grid, origin) = Grid3::from_points(droplet, true, false, 1).unwrap();
+        assert_eq!(origin, [0, 0, 0]);
+        assert_eq!(grid.dims(), [5, 5, 8]);
+        assert_eq!(grid.bounds(|&lava| lava), Some(([1, 1, 1], [3, 3, 6])));
+
+        let sides = NeighborhoodN::von_neumann();
+        let faces = |air: &dyn Fn(PosN<3>) -> bool| {
+            grid.positions()
+                .filter(|&pos| grid[pos])
+                .flat_map(|pos| grid.neighbors_of(pos, &sides))
+                .filter(|&neighbor| air(neighbor))
+                .count()
+        };
+        assert_eq!(faces(&|pos| !grid[pos]), 64);
+
+        let outside = grid.flood_fill([0, 0, 0], &sides, |_, &lava| !lava);
+        let mut exterior = Grid3::filled(false, grid.dims());
+        outside.into_iter().for_each(|pos| exterior[pos] = true);
+        assert_eq!(faces(&|pos| exterior[pos]), 58);
+    }
+}
@@ -0,0 +1,290 @@
+use super::{Grid, Pos};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The positions of each marker char found while parsing a grid.
+pub type Markers = HashMap<char, Vec<Pos>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// The text had no rows.
+    Empty,
+    /// A row had no cells, though other rows did.
+    Blank { row: usize },
+    /// A row had a different number of cells to the first row.
+    Ragged {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A cell failed to parse.
+    Cell {
+        row: usize,
+        col: usize,
+        cell: String,
+        message: String,
+    },
+    /// A layer of a 3-D grid failed to parse.
+    Layer {
+        layer: usize,
+        error: Box<ParseGridError>,
+    },
+    /// A layer of a 3-D grid had a different size to the first layer.
+    Mismatched {
+        layer: usize,
+        size: (usize, usize),
+        expected: (usize, usize),
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "Grid has no rows"),
+            ParseGridError::Blank { row } => write!(f, "Row {} is blank", row),
+            ParseGridError::Ragged {
+                row,
+                width,
+                expected,
+            } => write!(
+                f,
+                "Row {} has {} cells but the grid is {} wide",
+                row, width, expected
+            ),
+            ParseGridError::Cell {
+                row,
+                col,
+                cell,
+                message,
+            } => write!(
+                f,
+                "Cell {:?} at row {}, column {}: {}",
+                cell, row, col, message
+            ),
+            ParseGridError::Layer { layer, error } => write!(f, "Layer {}: {}", layer, error),
+            ParseGridError::Mismatched {
+                layer,
+                size,
+                expected,
+            } => write!(
+                f,
+                "Layer {} is {}x{} but the first layer is {}x{}",
+                layer, size.0, size.1, expected.0, expected.1
+            ),
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+impl<T> Grid<T> {
+    /// Builds a grid row by row from the lines of `text`, splitting
+    /// each line into cells with `cells` and parsing them with `parse`.
+    fn parse_rows<'a, E: fmt::Display>(
+        text: &'a str,
+        cells: fn(&'a str) -> Vec<&'a str>,
+        mut parse: impl FnMut(&'a str) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseGridError> {
+        let mut elements = Vec::new();
+        let mut width = None;
+        // Only trailing blank lines are dropped, as leading spaces may be cells
+        let text = text.trim_end_matches(['\n', '\r']);
+        let mut lines = text.lines().enumerate();
+        while let Some((row, line)) = lines.next() {
+            let line = cells(line);
+            if line.is_empty() {
+                // Only text with no cells at all is an empty grid
+                let rows_follow = lines.clone().any(|(_, line)| !cells(line).is_empty());
+                return Err(if width.is_some() || rows_follow {
+                    ParseGridError::Blank { row }
+                } else {
+                    ParseGridError::Empty
+                });
+            }
+            let expected = *width.get_or_insert(line.len());
+            if line.len() != expected {
+                return Err(ParseGridError::Ragged {
+                    row,
+                    width: line.len(),
+                    expected,
+                });
+            }
+            for (col, cell) in line.into_iter().enumerate() {
+                elements.push(parse(cell).map_err(|err| ParseGridError::Cell {
+                    row,
+                    col,
+                    cell: cell.to_string(),
+                    message: err.to_string(),
+                })?);
+            }
+        }
+        match width {
+            Some(width) => Ok(Grid::new(elements, width)),
+            None => Err(ParseGridError::Empty),
+        }
+    }
+
+    /// Parses a grid with one cell per character, mapping each with `parse`.
+    pub fn parse_with<E: fmt::Display>(
+        text: &str,
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseGridError> {
+        Grid::parse_rows(
+            text,
+            |line| {
+                line.char_indices()
+                    .map(|(i, c)| &line[i..i + c.len_utf8()])
+                    .collect()
+            },
+            |cell| parse(cell.chars().next().unwrap()),
+        )
+    }
+
+    /// Like `parse_with`, but the `markers` chars are recorded rather than
+    /// parsed: their cells get the paired base value and their positions are
+    /// returned by char.
+    pub fn parse_with_markers<E: fmt::Display>(
+        text: &str,
+        markers: &[(char, T)],
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<(Grid<T>, Markers), ParseGridError>
+    where
+        T: Clone,
+    {
+        // Flat indices of the markers, as the width isn't known until the
+        // first row has been parsed
+        let mut index = 0;
+        let mut marked = Vec::new();
+        let grid = Grid::parse_with(text, |c| {
+            index += 1;
+            match markers.iter().find(|(marker, _)| *marker == c) {
+                Some((_, base)) => {
+                    marked.push((c, index - 1));
+                    Ok(base.clone())
+                }
+                None => parse(c),
+            }
+        })?;
+        let mut positions = Markers::new();
+        marked.into_iter().for_each(|(c, index)| {
+            positions
+                .entry(c)
+                .or_default()
+                .push(grid.pos_of(index).unwrap())
+        });
+        Ok((grid, positions))
+    }
+
+    /// Parses a grid of whitespace separated cells, such as rows of
+    /// multi-digit numbers.
+    pub fn parse_fields(text: &str) -> Result<Grid<T>, ParseGridError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        Grid::parse_rows(text, |line| line.split_whitespace().collect(), str::parse)
+    }
+}
+
+/// Parses a grid with one cell per character, each parsed with `T: FromStr`.
+impl<T: FromStr> FromStr for Grid<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseGridError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| c.to_string().parse())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("#.\n.#\n", |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected # or ."),
+        })
+        .unwrap();
+        assert_eq!(grid.elements(), &vec![true, false, false, true]);
+        assert_eq!(
+            Grid::<usize>::from_str("12\n3x").err(),
+            Some(ParseGridError::Cell {
+                row: 1,
+                col: 1,
+                cell: String::from("x"),
+                message: String::from("invalid digit found in string"),
+            })
+        );
+    }
+    #[test]
+    fn test_parse_with_markers() {
+        let (grid, markers) = Grid::parse_with_markers(
+            "S.#\n.E.\n#.S",
+            &[('S', false), ('E', false)],
+            |c| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err("expected # or ."),
+            },
+        )
+        .unwrap();
+        assert_eq!(grid.iter().filter(|&&wall| wall).count(), 2);
+        assert_eq!(markers[&'S'], vec![Pos::new(0, 0), Pos::new(2, 2)]);
+        assert_eq!(markers[&'E'], vec![Pos::new(1, 1)]);
+        assert!(!markers.contains_key(&'#'));
+    }
+    #[test]
+    fn test_parse_fields() {
+        let grid: Grid<usize> = Grid::parse_fields("22 13  17\n 8  2 23\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[1], [8, 2, 23]);
+    }
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::<usize>::from_str("123\n45\n678").err(),
+            Some(ParseGridError::Ragged {
+                row: 1,
+                width: 2,
+                expected: 3
+            })
+        );
+        assert_eq!(
+            Grid::<usize>::from_str("\n").err(),
+            Some(ParseGridError::Empty)
+        );
+        assert_eq!(
+            Grid::<usize>::parse_fields("  \n  ").err(),
+            Some(ParseGridError::Empty)
+        );
+        // A blank row is an error in that row when other rows have cells
+        assert_eq!(
+            Grid::<usize>::from_str("\n12\n34").err(),
+            Some(ParseGridError::Blank { row: 0 })
+        );
+        assert_eq!(
+            Grid::<usize>::parse_fields("  \n1 2").err(),
+            Some(ParseGridError::Blank { row: 0 })
+        );
+        assert_eq!(
+            Grid::<usize>::from_str("12\n\n34")
+                .err()
+                .unwrap()
+                .to_string(),
+            "Row 1 is blank"
+        );
+        assert_eq!(
+            Grid::<usize>::parse_fields("1 2\n3 4 5")
+                .err()
+                .unwrap()
+                .to_string(),
+            "Row 1 has 3 cells but the grid is 2 wide"
+        );
+    }
+}
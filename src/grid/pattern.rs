@@ -0,0 +1,125 @@
+use super::{Grid, Offset, Pos, Symmetry};
+
+/// Where a pattern was found, and which orientation of it matched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The position of the oriented pattern's top left cell.
+    pub top_left: Pos,
+    pub symmetry: Symmetry,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The top left position of every place `pattern` occurs in the grid,
+    /// row by row. Pattern cells equal to `wildcard` match any cell.
+    pub fn find_pattern(&self, pattern: &Grid<T>, wildcard: Option<&T>) -> Vec<Pos> {
+        if pattern.width() > self.width() || pattern.height() > self.height() {
+            return Vec::new();
+        }
+        // Only the cells that must match need checking
+        let cells: Vec<(Offset, &T)> = pattern
+            .positions()
+            .zip(pattern.iter())
+            .filter(|&(_, value)| Some(value) != wildcard)
+            .map(|(pos, value)| (pos - Pos::ORIGIN, value))
+            .collect();
+        let across = (self.width() - pattern.width()) as isize;
+        let down = (self.height() - pattern.height()) as isize;
+        (0..=down)
+            .flat_map(|y| (0..=across).map(move |x| Pos::new(x, y)))
+            .filter(|&top_left| {
+                cells
+                    .iter()
+                    .all(|&(offset, value)| self[top_left + offset] == *value)
+            })
+            .collect()
+    }
+
+    /// Like `find_pattern`, but finding the pattern under each of the eight
+    /// rotations and flips. Orientations that give the same pattern as an
+    /// earlier one in `Symmetry::ALL` are skipped, so that symmetric patterns
+    /// are only found once at each place.
+    pub fn find_pattern_oriented(&self, pattern: &Grid<T>, wildcard: Option<&T>) -> Vec<Match>
+    where
+        T: Clone,
+    {
+        let mut oriented: Vec<(Symmetry, Grid<T>)> = Vec::new();
+        for symmetry in Symmetry::ALL {
+            let candidate = pattern.orient(symmetry);
+            if oriented.iter().all(|(_, seen)| *seen != candidate) {
+                oriented.push((symmetry, candidate));
+            }
+        }
+        oriented
+            .iter()
+            .flat_map(|(symmetry, pattern)| {
+                self.find_pattern(pattern, wildcard)
+                    .into_iter()
+                    .map(|top_left| Match {
+                        top_left,
+                        symmetry: *symmetry,
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod pattern_tests {
+    use super::*;
+
+    fn chars(text: &str) -> Grid<char> {
+        Grid::parse_with(text, Ok::<char, &str>).unwrap()
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid = chars("#.#.\n.###\n#.##");
+        assert_eq!(
+            grid.find_pattern(&chars("#.\n.#"), None),
+            vec![Pos::new(0, 0)]
+        );
+        assert_eq!(
+            grid.find_pattern(&chars("#?\n?#"), Some(&'?')),
+            vec![
+                Pos::new(0, 0),
+                Pos::new(2, 0),
+                Pos::new(1, 1),
+                Pos::new(2, 1)
+            ]
+        );
+        assert!(grid.find_pattern(&chars("#####"), None).is_empty());
+    }
+    #[test]
+    fn test_find_pattern_oriented() {
+        let grid = chars("....\n.#..\n.###\n....");
+        let found = grid.find_pattern_oriented(&chars("#..\n###"), None);
+        assert_eq!(
+            found,
+            vec![Match {
+                top_left: Pos::new(1, 1),
+                symmetry: Symmetry::Identity
+            }]
+        );
+        let found = grid.find_pattern_oriented(&chars("##\n#.\n#."), None);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].symmetry, Symmetry::Rotate270);
+    }
+    #[test]
+    fn test_word_search() {
+        let grid = chars(
+            "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX",
+        );
+        let straight = grid.find_pattern_oriented(&chars("XMAS"), None);
+        let diagonal = grid.find_pattern_oriented(&chars("X...\n.M..\n..A.\n...S"), Some(&'.'));
+        assert_eq!(straight.len() + diagonal.len(), 18);
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A cell position, with `y` growing downwards. Signed so that positions just
+/// off the edge of a grid can be represented and then rejected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Pos {
+        Pos { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Pos {
+        self + direction.offset()
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        let Offset { dx, dy } = other - self;
+        dx.unsigned_abs() + dy.unsigned_abs()
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// The difference between two positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Offset {
+    pub const fn new(dx: isize, dy: isize) -> Offset {
+        Offset { dx, dy }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:+},{:+})", self.dx, self.dy)
+    }
+}
+
+impl Add<Offset> for Pos {
+    type Output = Pos;
+
+    fn add(self, offset: Offset) -> Pos {
+        Pos::new(self.x + offset.dx, self.y + offset.dy)
+    }
+}
+
+impl AddAssign<Offset> for Pos {
+    fn add_assign(&mut self, offset: Offset) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<Offset> for Pos {
+    type Output = Pos;
+
+    fn sub(self, offset: Offset) -> Pos {
+        self + -offset
+    }
+}
+
+impl SubAssign<Offset> for Pos {
+    fn sub_assign(&mut self, offset: Offset) {
+        *self = *self - offset;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Offset;
+
+    fn sub(self, other: Pos) -> Offset {
+        Offset::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+
+    fn sub(self, other: Offset) -> Offset {
+        self + -other
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, n: isize) -> Offset {
+        Offset::new(self.dx * n, self.dy * n)
+    }
+}
+
+/// Compass directions on screen, so `Up` is towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction::Up => Offset::new(0, -1),
+            Direction::UpRight => Offset::new(1, -1),
+            Direction::Right => Offset::new(1, 0),
+            Direction::DownRight => Offset::new(1, 1),
+            Direction::Down => Offset::new(0, 1),
+            Direction::DownLeft => Offset::new(-1, 1),
+            Direction::Left => Offset::new(-1, 0),
+            Direction::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Direction::ORTHOGONAL.contains(&self)
+    }
+
+    /// Rotates clockwise by `eighths` of a turn.
+    fn rotate(self, eighths: usize) -> Direction {
+        let i = Direction::ALL.iter().position(|&d| d == self).unwrap();
+        Direction::ALL[(i + eighths) % 8]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod pos_tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let pos = Pos::new(2, 3);
+        assert_eq!(pos + Offset::new(-3, 1), Pos::new(-1, 4));
+        assert_eq!(pos - Offset::new(1, 1), Pos::new(1, 2));
+        assert_eq!(Pos::new(5, 1) - pos, Offset::new(3, -2));
+        assert_eq!(Offset::new(1, -2) * 3, Offset::new(3, -6));
+        assert_eq!(pos.manhattan(Pos::new(-1, 5)), 5);
+        assert_eq!(pos.step(Direction::UpLeft), Pos::new(1, 2));
+    }
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+        assert!(Direction::ALL
+            .iter()
+            .all(|&d| d.offset() == -d.opposite().offset()));
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+}
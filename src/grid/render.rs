@@ -0,0 +1,198 @@
+use super::{Grid, Pos};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A terminal colour for highlighting cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+}
+
+impl Color {
+    /// The ANSI escape code that sets this as the text colour.
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+        }
+    }
+
+    /// The colour as red, green and blue, for use in image palettes.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [205, 49, 49],
+            Color::Green => [13, 188, 121],
+            Color::Yellow => [229, 229, 16],
+            Color::Blue => [36, 114, 200],
+            Color::Magenta => [188, 63, 188],
+            Color::Cyan => [17, 168, 205],
+            Color::BrightRed => [241, 76, 76],
+            Color::BrightGreen => [35, 209, 139],
+            Color::BrightYellow => [245, 245, 67],
+            Color::BrightBlue => [59, 142, 234],
+            Color::BrightMagenta => [214, 112, 214],
+            Color::BrightCyan => [41, 184, 219],
+        }
+    }
+}
+
+type Format<'a, T> = Box<dyn Fn(Pos, &T) -> String + 'a>;
+type Highlight<'a, T> = (Box<dyn Fn(Pos, &T) -> bool + 'a>, Color);
+
+/// Draws a grid as text, one row per line. Built with `Grid::render` or
+/// `Grid::render_with` and then configured by chaining, e.g.
+/// `grid.render().separator(" ").align().highlight(path, Color::Red)`.
+pub struct GridRenderer<'a, T> {
+    grid: &'a Grid<T>,
+    format: Format<'a, T>,
+    separator: String,
+    align: bool,
+    highlights: Vec<Highlight<'a, T>>,
+}
+
+impl<T> Grid<T> {
+    /// Renders each cell with its `Display` implementation.
+    pub fn render(&self) -> GridRenderer<'_, T>
+    where
+        T: fmt::Display,
+    {
+        self.render_with(|_, value| value.to_string())
+    }
+
+    /// Renders each cell as `format(pos, value)`.
+    pub fn render_with<'a>(
+        &'a self,
+        format: impl Fn(Pos, &T) -> String + 'a,
+    ) -> GridRenderer<'a, T> {
+        GridRenderer {
+            grid: self,
+            format: Box::new(format),
+            separator: String::new(),
+            align: false,
+            highlights: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> GridRenderer<'a, T> {
+    /// Puts `separator` between the cells of each row.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Right aligns every cell to the width of the widest one.
+    pub fn align(mut self) -> Self {
+        self.align = true;
+        self
+    }
+
+    /// Colours the cells at `positions`.
+    pub fn highlight(self, positions: impl IntoIterator<Item = Pos>, color: Color) -> Self {
+        let positions: HashSet<Pos> = positions.into_iter().collect();
+        self.highlight_where(move |pos, _| positions.contains(&pos), color)
+    }
+
+    /// Colours the cells for which `predicate` holds. Where highlights
+    /// overlap, the one added last wins.
+    pub fn highlight_where(
+        mut self,
+        predicate: impl Fn(Pos, &T) -> bool + 'a,
+        color: Color,
+    ) -> Self {
+        self.highlights.push((Box::new(predicate), color));
+        self
+    }
+}
+
+impl<T> fmt::Display for GridRenderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<String> = self
+            .grid
+            .positions()
+            .zip(self.grid.iter())
+            .map(|(pos, value)| (self.format)(pos, value))
+            .collect();
+        let width = match self.align {
+            true => cells
+                .iter()
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0),
+            false => 0,
+        };
+
+        for (index, (pos, value)) in self.grid.positions().zip(self.grid.iter()).enumerate() {
+            if pos.x == 0 && pos.y > 0 {
+                writeln!(f)?;
+            } else if pos.x > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            let color = self
+                .highlights
+                .iter()
+                .rev()
+                .find(|(predicate, _)| predicate(pos, value))
+                .map(|&(_, color)| color);
+            // Pad before colouring, as the escape codes take up no space
+            let cell = format!("{:>width$}", cells[index], width = width);
+            match color {
+                Some(color) => write!(f, "\x1b[{}m{}\x1b[0m", color.code(), cell)?,
+                None => write!(f, "{}", cell)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod render_tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::new(vec![1, 20, 300, 4], 2);
+        assert_eq!(grid.render().to_string(), "120\n3004");
+        assert_eq!(
+            grid.render().separator(" ").align().to_string(),
+            "  1  20\n300   4"
+        );
+        assert_eq!(
+            grid.render_with(|pos, _| pos.to_string())
+                .separator("|")
+                .to_string(),
+            "(0,0)|(1,0)\n(0,1)|(1,1)"
+        );
+    }
+    #[test]
+    fn test_highlight() {
+        let grid = Grid::new(vec![0, 1, 0, 2], 2);
+        let rendered = grid
+            .render()
+            .highlight_where(|_, &x| x == 0, Color::Yellow)
+            .highlight([Pos::new(0, 1)], Color::Red)
+            .to_string();
+        assert_eq!(rendered, "\x1b[33m0\x1b[0m1\n\x1b[31m0\x1b[0m2");
+    }
+}
@@ -0,0 +1,287 @@
+//! Shortest paths between cells of a grid, and distances from sets of cells.
+//!
+//! Costs are for entering a cell, so the start cell's own cost is never
+//! counted. A cost of `None` makes a cell impassable.
+
+use super::{Grid, Neighborhood, Pos};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path {
+    pub cost: usize,
+    /// Every position visited, from the start to the goal inclusive.
+    pub path: Vec<Pos>,
+}
+
+/// Walks `previous` back from the goal to rebuild the path to it.
+fn trace<T>(grid: &Grid<T>, previous: &[Option<usize>], goal: usize, cost: usize) -> Path {
+    let mut path = vec![goal];
+    while let Some(index) = previous[*path.last().unwrap()] {
+        path.push(index);
+    }
+    Path {
+        cost,
+        path: path
+            .into_iter()
+            .rev()
+            .map(|index| grid.pos_of(index).unwrap())
+            .collect(),
+    }
+}
+
+/// The path with the fewest steps, where each step costs 1.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    neighborhood: &Neighborhood,
+    start: Pos,
+    goal: Pos,
+    mut passable: impl FnMut(Pos, &T) -> bool,
+) -> Option<Path> {
+    let start = grid.index_of(start)?;
+    let goal = grid.index_of(goal)?;
+    let mut previous = vec![None; grid.size()];
+    let mut seen = vec![false; grid.size()];
+    let mut queue = VecDeque::from([(start, 0)]);
+    seen[start] = true;
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if index == goal {
+            return Some(trace(grid, &previous, goal, steps));
+        }
+        let pos = grid.pos_of(index).unwrap();
+        for (neighbor, value) in grid.neighbor_cells(pos, neighborhood) {
+            let neighbor_index = grid.index_of(neighbor).unwrap();
+            if !seen[neighbor_index] && passable(neighbor, value) {
+                seen[neighbor_index] = true;
+                previous[neighbor_index] = Some(index);
+                queue.push_back((neighbor_index, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest path, where entering a cell costs `cost`.
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    neighborhood: &Neighborhood,
+    start: Pos,
+    goal: Pos,
+    cost: impl FnMut(Pos, &T) -> Option<usize>,
+) -> Option<Path> {
+    astar(grid, neighborhood, start, goal, cost, |_| 0)
+}
+
+/// The cheapest path, exploring cells in order of their cost so far plus
+/// `heuristic`. The heuristic must never overestimate the remaining cost to
+/// the goal, or the path found may not be the cheapest.
+pub fn astar<T>(
+    grid: &Grid<T>,
+    neighborhood: &Neighborhood,
+    start: Pos,
+    goal: Pos,
+    mut cost: impl FnMut(Pos, &T) -> Option<usize>,
+    mut heuristic: impl FnMut(Pos) -> usize,
+) -> Option<Path> {
+    let start_index = grid.index_of(start)?;
+    let goal_index = grid.index_of(goal)?;
+    let mut previous = vec![None; grid.size()];
+    let mut distances = vec![usize::MAX; grid.size()];
+    let mut queue = BinaryHeap::new();
+    distances[start_index] = 0;
+    queue.push(Reverse((heuristic(start), 0, start_index)));
+
+    while let Some(Reverse((_, distance, index))) = queue.pop() {
+        if index == goal_index {
+            return Some(trace(grid, &previous, goal_index, distance));
+        }
+        // Skip stale entries left behind when a cheaper route was found
+        if distance > distances[index] {
+            continue;
+        }
+        let pos = grid.pos_of(index).unwrap();
+        for (neighbor, value) in grid.neighbor_cells(pos, neighborhood) {
+            let step = match cost(neighbor, value) {
+                Some(step) => step,
+                None => continue,
+            };
+            let neighbor_index = grid.index_of(neighbor).unwrap();
+            let new_distance = distance + step;
+            if new_distance < distances[neighbor_index] {
+                distances[neighbor_index] = new_distance;
+                previous[neighbor_index] = Some(index);
+                queue.push(Reverse((
+                    new_distance + heuristic(neighbor),
+                    new_distance,
+                    neighbor_index,
+                )));
+            }
+        }
+    }
+    None
+}
+
+impl<T> Grid<T> {
+    /// The fewest steps from any of `sources` to each cell, moving only into
+    /// cells matching `passable`, or `None` where no source can reach.
+    /// Sources off the grid are ignored.
+    pub fn distance_map(
+        &self,
+        sources: impl IntoIterator<Item = Pos>,
+        neighborhood: &Neighborhood,
+        mut passable: impl FnMut(Pos, &T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(vec![None; self.size()], self.width());
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut(source) {
+                *distance = Some(0);
+                queue.push_back(source);
+            }
+        }
+        while let Some(pos) = queue.pop_front() {
+            let distance = distances[pos].unwrap();
+            for (neighbor, value) in self.neighbor_cells(pos, neighborhood) {
+                if distances[neighbor].is_none() && passable(neighbor, value) {
+                    distances[neighbor] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Like `distance_map`, but where entering a cell costs `cost`, as in
+    /// `dijkstra`.
+    pub fn weighted_distance_map(
+        &self,
+        sources: impl IntoIterator<Item = Pos>,
+        neighborhood: &Neighborhood,
+        mut cost: impl FnMut(Pos, &T) -> Option<usize>,
+    ) -> Grid<Option<usize>> {
+        let mut distances: Grid<Option<usize>> = Grid::new(vec![None; self.size()], self.width());
+        let mut queue = BinaryHeap::new();
+        for source in sources {
+            if let Some(index) = self.index_of(source) {
+                distances[source] = Some(0);
+                queue.push(Reverse((0, index)));
+            }
+        }
+        while let Some(Reverse((distance, index))) = queue.pop() {
+            let pos = self.pos_of(index).unwrap();
+            // Skip stale entries left behind when a cheaper route was found
+            if distances[pos].is_some_and(|best| distance > best) {
+                continue;
+            }
+            for (neighbor, value) in self.neighbor_cells(pos, neighborhood) {
+                let step = match cost(neighbor, value) {
+                    Some(step) => step,
+                    None => continue,
+                };
+                let new_distance = distance + step;
+                if distances[neighbor].is_none_or(|best| new_distance < best) {
+                    distances[neighbor] = Some(new_distance);
+                    queue.push(Reverse((new_distance, self.index_of(neighbor).unwrap())));
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    const MAZE: &str = "..#.
+.##.
+....
+#.#.";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse_with(MAZE, |c| Ok::<bool, &str>(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let path = bfs(
+            &grid,
+            &Neighborhood::VonNeumann,
+            Pos::new(0, 0),
+            Pos::new(3, 0),
+            |_, &wall| !wall,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.path.len(), 8);
+        assert_eq!(path.path[0], Pos::new(0, 0));
+        assert_eq!(path.path[7], Pos::new(3, 0));
+        assert!(path.path.iter().all(|&pos| !grid[pos]));
+        assert!(path
+            .path
+            .windows(2)
+            .all(|step| step[0].manhattan(step[1]) == 1));
+
+        let into_wall = bfs(
+            &grid,
+            &Neighborhood::VonNeumann,
+            Pos::new(0, 0),
+            Pos::new(0, 3),
+            |_, &wall| !wall,
+        );
+        assert_eq!(into_wall, None);
+    }
+    #[test]
+    fn test_weighted() {
+        let grid: Grid<usize> = "131\n191\n111".parse().unwrap();
+        let cost = |_, &risk: &usize| Some(risk);
+        let (start, goal) = (Pos::new(0, 0), Pos::new(2, 2));
+        let path = dijkstra(&grid, &Neighborhood::VonNeumann, start, goal, cost).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.path[1], Pos::new(0, 1));
+        let path = astar(&grid, &Neighborhood::VonNeumann, start, goal, cost, |pos| {
+            pos.manhattan(goal)
+        })
+        .unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(
+            dijkstra(&grid, &Neighborhood::Moore, start, goal, cost)
+                .unwrap()
+                .cost,
+            3
+        );
+    }
+    #[test]
+    fn test_distance_map() {
+        let grid = maze();
+        let distances =
+            grid.distance_map([Pos::new(0, 0)], &Neighborhood::VonNeumann, |_, &wall| {
+                !wall
+            });
+        assert_eq!(distances[Pos::new(3, 0)], Some(7));
+        assert_eq!(distances[Pos::new(2, 0)], None);
+        assert_eq!(distances[0], [Some(0), Some(1), None, Some(7)]);
+
+        // The distance to the nearer of two sources
+        let distances = grid.distance_map(
+            [Pos::new(0, 0), Pos::new(3, 3)],
+            &Neighborhood::VonNeumann,
+            |_, &wall| !wall,
+        );
+        assert_eq!(distances[0], [Some(0), Some(1), None, Some(3)]);
+        assert_eq!(distances[Pos::new(1, 2)], Some(3));
+    }
+    #[test]
+    fn test_weighted_distance_map() {
+        let grid: Grid<usize> = "131\n191\n111".parse().unwrap();
+        let distances =
+            grid.weighted_distance_map([Pos::ORIGIN], &Neighborhood::VonNeumann, |_, &risk| {
+                Some(risk)
+            });
+        assert_eq!(distances[0], [Some(0), Some(3), Some(4)]);
+        assert_eq!(distances[1], [Some(1), Some(10), Some(5)]);
+        assert_eq!(distances[2], [Some(2), Some(3), Some(4)]);
+    }
+}
@@ -0,0 +1,141 @@
+use super::{Grid, Pos};
+use std::collections::hash_map::{self, HashMap};
+
+/// An unbounded grid with signed coordinates, where only cells that have been
+/// set are stored and every other cell has the background value.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    background: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(background: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            background,
+        }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Changes the value of every cell that hasn't been set.
+    pub fn set_background(&mut self, background: T) {
+        self.background = background;
+    }
+
+    pub fn get(&self, pos: Pos) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.background)
+    }
+
+    /// The cell at `pos`, storing the background value there first if it
+    /// hasn't been set.
+    pub fn get_mut(&mut self, pos: Pos) -> &mut T
+    where
+        T: Clone,
+    {
+        let background = &self.background;
+        self.cells.entry(pos).or_insert_with(|| background.clone())
+    }
+
+    pub fn set(&mut self, pos: Pos, value: T) {
+        self.cells.insert(pos, value);
+    }
+
+    /// Resets the cell at `pos` to the background, returning its old value if
+    /// it had been set.
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that have been set, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Pos, T> {
+        self.cells.iter()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding
+    /// every set cell, or `None` if no cells are set.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.cells.keys().fold(None, |bounds, &pos| match bounds {
+            None => Some((pos, pos)),
+            Some((min, max)) => Some((
+                Pos::new(pos.x.min(min.x), pos.y.min(min.y)),
+                Pos::new(pos.x.max(max.x), pos.y.max(max.y)),
+            )),
+        })
+    }
+
+    /// A dense copy of the bounding box, along with the position of its top
+    /// left cell. Returns `None` if no cells are set.
+    pub fn to_grid(&self) -> Option<(Grid<T>, Pos)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds()?;
+        let width = (max.x - min.x + 1) as usize;
+        let elements = (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| Pos::new(x, y)))
+            .map(|pos| self.get(pos).clone())
+            .collect();
+        Some((Grid::new(elements, width), min))
+    }
+
+    /// A sparse copy of `grid` with its top left cell at `origin`, storing
+    /// only the cells that differ from the background.
+    pub fn from_grid(grid: &Grid<T>, origin: Pos, background: T) -> SparseGrid<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let cells = grid
+            .positions()
+            .zip(grid.iter())
+            .filter(|(_, value)| **value != background)
+            .map(|(pos, value)| (origin + (pos - Pos::ORIGIN), value.clone()))
+            .collect();
+        SparseGrid { cells, background }
+    }
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse() {
+        let mut sparse = SparseGrid::new(0);
+        *sparse.get_mut(Pos::new(-2, 3)) += 1;
+        *sparse.get_mut(Pos::new(-2, 3)) += 1;
+        sparse.set(Pos::new(1, -1), 5);
+        assert_eq!(*sparse.get(Pos::new(-2, 3)), 2);
+        assert_eq!(*sparse.get(Pos::new(100, 100)), 0);
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.bounds(), Some((Pos::new(-2, -1), Pos::new(1, 3))));
+        assert_eq!(sparse.remove(Pos::new(1, -1)), Some(5));
+        assert_eq!(sparse.bounds(), Some((Pos::new(-2, 3), Pos::new(-2, 3))));
+        sparse.remove(Pos::new(-2, 3));
+        assert!(sparse.is_empty());
+        assert_eq!(sparse.bounds(), None);
+    }
+    #[test]
+    fn test_dense_round_trip() {
+        let grid: Grid<char> = Grid::new("#...##".chars().collect(), 3);
+        let sparse = SparseGrid::from_grid(&grid, Pos::new(-1, 5), '.');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(*sparse.get(Pos::new(-1, 5)), '#');
+        assert_eq!(*sparse.get(Pos::new(0, 5)), '.');
+        let (dense, origin) = sparse.to_grid().unwrap();
+        assert_eq!(origin, Pos::new(-1, 5));
+        assert_eq!(dense.elements(), grid.elements());
+    }
+}
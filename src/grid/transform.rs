@@ -0,0 +1,300 @@
+use super::{Grid, Pos};
+
+/// One of the eight ways to rotate and flip a grid onto itself. Rotations
+/// are clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    /// Mirroring along the main diagonal, from top left to bottom right.
+    Transpose,
+    /// Mirroring along the other diagonal, from top right to bottom left.
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+}
+
+/// Transforms that build a new grid from this one. Rotations are clockwise.
+impl<T: Clone> Grid<T> {
+    /// Builds a `width` by `height` grid taking each cell from `source`, with
+    /// the same boundary as this one.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Pos) -> Grid<T> {
+        let elements = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Grid::new(elements, width).with_boundary(self.boundary.clone())
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height(), self.width(), |x, y| pos(y, x))
+    }
+
+    pub fn rotate_90(&self) -> Grid<T> {
+        let height = self.height();
+        self.remap(height, self.width(), |x, y| pos(y, height - 1 - x))
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(width, height, |x, y| pos(width - 1 - x, height - 1 - y))
+    }
+
+    pub fn rotate_270(&self) -> Grid<T> {
+        let width = self.width();
+        self.remap(self.height(), width, |x, y| pos(width - 1 - y, x))
+    }
+
+    /// Mirrors the grid along the diagonal from top right to bottom left.
+    pub fn anti_transpose(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(height, width, |x, y| pos(width - 1 - y, height - 1 - x))
+    }
+
+    /// Applies `symmetry` to the grid.
+    pub fn orient(&self, symmetry: Symmetry) -> Grid<T> {
+        match symmetry {
+            Symmetry::Identity => self.clone(),
+            Symmetry::Rotate90 => self.rotate_90(),
+            Symmetry::Rotate180 => self.rotate_180(),
+            Symmetry::Rotate270 => self.rotate_270(),
+            Symmetry::FlipHorizontal => self.flip_horizontal(),
+            Symmetry::FlipVertical => self.flip_vertical(),
+            Symmetry::Transpose => self.transpose(),
+            Symmetry::AntiTranspose => self.anti_transpose(),
+        }
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width();
+        self.remap(width, self.height(), |x, y| pos(width - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height();
+        self.remap(self.width(), height, |x, y| pos(x, height - 1 - y))
+    }
+
+    /// The `width` by `height` block with its top left cell at `top_left`, or
+    /// `None` if the block doesn't fit inside the grid.
+    pub fn subgrid(&self, top_left: Pos, width: usize, height: usize) -> Option<Grid<T>> {
+        let bottom_right = Pos::new(
+            top_left.x + width as isize - 1,
+            top_left.y + height as isize - 1,
+        );
+        if width == 0 || height == 0 || !self.contains(top_left) || !self.contains(bottom_right) {
+            return None;
+        }
+        Some(self.remap(width, height, |x, y| {
+            Pos::new(top_left.x + x as isize, top_left.y + y as isize)
+        }))
+    }
+
+    /// The block between the inclusive `(top_left, bottom_right)` corners,
+    /// such as the bounds of a component, clamped to the grid.
+    pub fn crop(&self, (min, max): (Pos, Pos)) -> Option<Grid<T>> {
+        let min = Pos::new(min.x.max(0), min.y.max(0));
+        let max = Pos::new(
+            max.x.min(self.width() as isize - 1),
+            max.y.min(self.height() as isize - 1),
+        );
+        if min.x > max.x || min.y > max.y {
+            return None;
+        }
+        self.subgrid(
+            min,
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+        )
+    }
+
+    /// Folds the rows below `line` up over the rows above it, dropping row
+    /// `line` itself. Each folded cell is combined with the one it lands on
+    /// using `merge(kept, folded)`, and cells with nothing over them are
+    /// kept as they are.
+    ///
+    /// The result is as tall as the taller half, with the row next to the
+    /// fold at the bottom, so folds away from the middle grow the grid above
+    /// the rows that were kept.
+    pub fn fold_up(&self, line: usize, mut merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        assert!(
+            line < self.height(),
+            "cannot fold a grid {} tall up along row {}",
+            self.height(),
+            line
+        );
+        let height = line.max(self.height() - line - 1);
+        let elements = (0..height)
+            .flat_map(|y| (0..self.width()).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                // Both halves meet this row at the same distance from the fold
+                let distance = height - y;
+                let kept = line.checked_sub(distance).map(|y| &self[pos(x, y)]);
+                let folded = self.get(pos(x, line + distance));
+                match (kept, folded) {
+                    (Some(kept), Some(folded)) => merge(kept, folded),
+                    (Some(cell), None) | (None, Some(cell)) => cell.clone(),
+                    (None, None) => unreachable!("row {} is in neither half", y),
+                }
+            })
+            .collect();
+        Grid::new(elements, self.width()).with_boundary(self.boundary.clone())
+    }
+
+    /// Folds the columns right of `line` over the columns left of it, like
+    /// `fold_up`.
+    pub fn fold_left(&self, line: usize, merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        self.transpose().fold_up(line, merge).transpose()
+    }
+
+    /// Repeats the grid `across` times horizontally and `down` times
+    /// vertically, mapping each cell with `f(value, tile_x, tile_y)`.
+    pub fn tile(
+        &self,
+        across: usize,
+        down: usize,
+        mut f: impl FnMut(&T, usize, usize) -> T,
+    ) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        let elements = (0..height * down)
+            .flat_map(|y| (0..width * across).map(move |x| (x, y)))
+            .map(|(x, y)| f(&self[pos(x % width, y % height)], x / width, y / height))
+            .collect();
+        Grid::new(elements, width * across).with_boundary(self.boundary.clone())
+    }
+}
+
+fn pos(x: usize, y: usize) -> Pos {
+    Pos::new(x as isize, y as isize)
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+
+    fn grid() -> Grid<usize> {
+        // 123
+        // 456
+        Grid::new((1..=6).collect(), 3)
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(grid().transpose().elements(), &vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(grid().rotate_90().elements(), &vec![4, 1, 5, 2, 6, 3]);
+        assert_eq!(grid().rotate_90().width(), 2);
+        assert_eq!(grid().rotate_180().elements(), &vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(grid().rotate_270().elements(), &vec![3, 6, 2, 5, 1, 4]);
+        assert_eq!(
+            grid().rotate_90().rotate_270().elements(),
+            grid().elements()
+        );
+        assert_eq!(grid().flip_horizontal().elements(), &vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(grid().flip_vertical().elements(), &vec![4, 5, 6, 1, 2, 3]);
+        assert_eq!(grid().anti_transpose().elements(), &vec![6, 3, 5, 2, 4, 1]);
+        assert_eq!(
+            grid().orient(Symmetry::AntiTranspose).elements(),
+            grid().rotate_90().flip_vertical().elements()
+        );
+    }
+    #[test]
+    fn test_subgrids() {
+        let grid = grid();
+        assert_eq!(
+            grid.subgrid(Pos::new(1, 0), 2, 2).unwrap().elements(),
+            &vec![2, 3, 5, 6]
+        );
+        assert!(grid.subgrid(Pos::new(2, 0), 2, 1).is_none());
+        assert_eq!(
+            grid.crop((Pos::new(-5, 1), Pos::new(1, 9)))
+                .unwrap()
+                .elements(),
+            &vec![4, 5]
+        );
+        assert!(grid.crop((Pos::new(3, 0), Pos::new(5, 1))).is_none());
+    }
+    #[test]
+    fn test_folds() {
+        let paper: Grid<bool> =
+            Grid::parse_with("#..\n...\n..#\n#..\n.#.", |c| Ok::<bool, &str>(c == '#')).unwrap();
+        let folded = paper.fold_up(2, |&a, &b| a || b);
+        assert_eq!(
+            folded.elements(),
+            &vec![true, true, false, true, false, false]
+        );
+        let folded = folded.fold_left(1, |&a, &b| a || b);
+        assert_eq!(folded.elements(), &vec![true, true]);
+    }
+    #[test]
+    fn test_asymmetric_folds() {
+        // 1
+        // 2
+        // 3
+        // 4
+        let column = Grid::new((1..=4).collect(), 1);
+        // The lower half is longer, so it hangs past the top of the kept row
+        assert_eq!(
+            column.fold_up(0, |&a, &b| a * 10 + b).elements(),
+            &vec![4, 3, 2]
+        );
+        assert_eq!(
+            column.fold_up(1, |&a, &b| a * 10 + b).elements(),
+            &vec![4, 13]
+        );
+        // The upper half is longer, so the folded row only covers its bottom
+        assert_eq!(
+            column.fold_up(3, |&a, &b| a * 10 + b).elements(),
+            &vec![1, 2, 3]
+        );
+        assert_eq!(
+            column.fold_up(2, |&a, &b| a * 10 + b).elements(),
+            &vec![1, 24]
+        );
+
+        let row = column.transpose();
+        assert_eq!(
+            row.fold_left(1, |&a, &b| a * 10 + b).elements(),
+            &vec![4, 13]
+        );
+        assert_eq!(
+            row.fold_left(2, |&a, &b| a * 10 + b).elements(),
+            &vec![1, 24]
+        );
+    }
+    #[test]
+    fn test_fold_to_empty() {
+        // Folding a single row away leaves nothing, which can still be
+        // transposed without dividing by a width of zero
+        let row = Grid::new(vec![1, 2, 3], 3);
+        let empty = row.fold_up(0, |&a, &b| a + b);
+        assert_eq!((empty.size(), empty.height()), (0, 0));
+        let empty = empty.transpose();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.transpose().size(), 0);
+    }
+    #[test]
+    fn test_tile() {
+        let tiled = Grid::new(vec![8, 9], 2).tile(3, 2, |&risk, x, y| (risk + x + y - 1) % 9 + 1);
+        assert_eq!(tiled.width(), 6);
+        assert_eq!(tiled[0], [8, 9, 9, 1, 1, 2]);
+        assert_eq!(tiled[1], [9, 1, 1, 2, 2, 3]);
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Grid, Offset, Pos};
+use std::iter::Sum;
+use std::ops::Mul;
+
+/// A view of a block of a grid, which may hang over its edges. Cells past
+/// the edges are looked up through the grid's boundary.
+#[derive(Clone, Copy, Debug)]
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Pos,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The position in the grid of the window's top left cell.
+    pub fn top_left(&self) -> Pos {
+        self.top_left
+    }
+
+    /// The cell `(x, y)` from the window's top left, or `None` if it is off
+    /// the grid and the boundary gives it no value.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.grid
+            .get_bounded(self.top_left + Offset::new(x as isize, y as isize))
+    }
+
+    /// Every cell of the window row by row, as `get` would give them.
+    pub fn cells(&self) -> impl Iterator<Item = Option<&'a T>> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| self.get(x, y)))
+    }
+}
+
+impl<T> Grid<T> {
+    /// The `width` by `height` window centred on `center`. Windows with an
+    /// even size reach further right and down than left and up.
+    pub fn window(&self, center: Pos, width: usize, height: usize) -> Window<'_, T> {
+        let reach = Offset::new((width as isize - 1) / 2, (height as isize - 1) / 2);
+        Window {
+            grid: self,
+            top_left: center - reach,
+            width,
+            height,
+        }
+    }
+
+    /// The window centred on each cell, row by row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Window<'_, T>> {
+        self.positions()
+            .map(move |pos| self.window(pos, width, height))
+    }
+
+    /// A grid of the same size made by passing the window centred on each
+    /// cell to `f`.
+    pub fn map_window<U>(
+        &self,
+        width: usize,
+        height: usize,
+        mut f: impl FnMut(&Window<'_, T>) -> U,
+    ) -> Grid<U> {
+        let elements = self
+            .windows(width, height)
+            .map(|window| f(&window))
+            .collect();
+        Grid::new(elements, self.width())
+    }
+
+    /// Sums each cell's window weighted by `kernel`, which is centred on the
+    /// cell like `window`. Cells the boundary gives no value add nothing.
+    pub fn convolve<K, U>(&self, kernel: &Grid<K>) -> Grid<U>
+    where
+        T: Clone + Mul<K, Output = U>,
+        K: Clone,
+        U: Sum<U>,
+    {
+        self.map_window(kernel.width(), kernel.height(), |window| {
+            window
+                .cells()
+                .zip(kernel.iter())
+                .filter_map(|(cell, weight)| Some(cell?.clone() * weight.clone()))
+                .sum()
+        })
+    }
+}
+
+#[cfg(test)]
+mod window_tests {
+    use super::*;
+    use crate::grid::Boundary;
+
+    fn grid() -> Grid<usize> {
+        // 123
+        // 456
+        // 789
+        Grid::new((1..=9).collect(), 3)
+    }
+
+    #[test]
+    fn test_windows() {
+        let grid = grid();
+        let window = grid.window(Pos::ORIGIN, 3, 3);
+        assert_eq!(window.top_left(), Pos::new(-1, -1));
+        assert_eq!(window.get(1, 1), Some(&1));
+        assert_eq!(window.get(0, 0), None);
+        assert_eq!(window.cells().flatten().count(), 4);
+        assert_eq!(
+            grid.window(Pos::new(1, 1), 2, 2)
+                .cells()
+                .flatten()
+                .collect::<Vec<_>>(),
+            vec![&5, &6, &8, &9]
+        );
+        assert_eq!(grid.windows(3, 1).count(), 9);
+
+        let grid = grid.with_boundary(Boundary::Wrap);
+        let window = grid.window(Pos::ORIGIN, 3, 3);
+        assert_eq!(window.get(0, 0), Some(&9));
+    }
+    #[test]
+    fn test_map_window() {
+        let sums = grid().map_window(3, 3, |window| window.cells().flatten().sum::<usize>());
+        assert_eq!(sums[0], [12, 21, 16]);
+        assert_eq!(sums[1], [27, 45, 33]);
+        let maxima = grid()
+            .with_boundary(Boundary::Fixed(100))
+            .map_window(1, 3, |window| *window.cells().flatten().max().unwrap());
+        assert_eq!(maxima[1], [7, 8, 9]);
+        assert_eq!(maxima[2], [100, 100, 100]);
+    }
+    #[test]
+    fn test_convolve() {
+        // A 3x3 block of bits read as a binary number, top left first
+        let kernel: Grid<usize> = Grid::new((0..9).rev().map(|bit| 1 << bit).collect(), 3);
+        let bits = Grid::new(vec![1, 0, 0, 1], 2).with_boundary(Boundary::Fixed(0));
+        let numbers = bits.convolve(&kernel);
+        assert_eq!(
+            numbers.elements(),
+            &vec![0b000_010_001, 0b000_100_010, 0b010_001_000, 0b100_010_000]
+        );
+        let laplacian: Grid<isize> = Grid::new(vec![0, 1, 0, 1, -4, 1, 0, 1, 0], 3);
+        let flat = Grid::new(vec![2; 9], 3).with_boundary(Boundary::Reflect);
+        assert!(flat.convolve(&laplacian).iter().all(|&x| x == 0));
+    }
+}
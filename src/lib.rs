@@ -1,17 +1,32 @@
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::Write;
+use std::path::Path;
 use std::process::Command;
 
+pub mod automaton;
 pub mod bits;
+pub mod cycle;
+pub mod geom;
 pub mod grid;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod repl;
+pub mod runner;
+pub mod serve;
+pub mod status;
+#[cfg(target_os = "linux")]
+pub mod watch;
+pub mod y2021;
+
+pub const YEAR: u16 = 2021;
 
 pub fn hello() {
     println!("Hello, world! - lib");
 }
 
 pub fn get_input(day: u8) -> String {
-    let path = format!("input/day{}.txt", day);
+    let path = runner::input_path(day);
     if let Ok(mut input_file) = File::open(&path) {
         let mut input = String::new();
         input_file.read_to_string(&mut input).unwrap();
@@ -21,9 +36,9 @@ pub fn get_input(day: u8) -> String {
     }
 }
 
-fn download_input(day: u8, path: &str) -> String {
-    println!("Downloading input for day {} to {}...", day, path);
-    let url = format!("https://adventofcode.com/2021/day/{}/input", day);
+fn download_input(day: u8, path: &Path) -> String {
+    println!("Downloading input for day {} to {}...", day, path.display());
+    let url = format!("https://adventofcode.com/{}/day/{}/input", YEAR, day);
     let session = format!("session={}", get_session());
     let input = Command::new("curl")
         .arg(url)
@@ -35,7 +50,7 @@ fn download_input(day: u8, path: &str) -> String {
         .stdout;
 
     let mut input_file = File::create(path).unwrap();
-    input_file.write(&input).unwrap();
+    input_file.write_all(&input).unwrap();
     String::from_utf8_lossy(&input).into()
 }
 
@@ -1,8 +1,84 @@
-use aoc::*;
+use std::env;
+use std::fs;
+use std::process;
 
-fn main() {
-    println!("Hello, world!");
+const USAGE: &str = "Usage: aoc <command>
+
+Commands:
+    repl <day> [input]
+                   Explore a day's parsed input (or an example file) interactively
+    run <day>      Solve both parts of a day against its puzzle input
+    serve [port]   Serve the solvers over HTTP on localhost (default port 2021)
+    status         Show stars, failing days and runtimes for the calendar
+    watch <day>    Re-run a day's examples and parts whenever it is saved";
 
-    hello();
-    get_input(1);
+fn parse_day(arg: Option<&String>) -> u8 {
+    match arg.map(|day| day.trim_start_matches("day").parse()) {
+        Some(Ok(day)) => day,
+        _ => {
+            eprintln!("Expected a day, e.g. `11` or `day11`\n\n{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|command| command.as_str()) {
+        Some("repl") => {
+            let day = parse_day(args.get(1));
+            if !aoc::repl::has_session(day) {
+                eprintln!("Day {} has no REPL commands", day);
+                process::exit(1);
+            }
+            let input = match args.get(2) {
+                Some(path) => fs::read_to_string(path),
+                None => Ok(aoc::get_input(day)),
+            };
+            input.and_then(|input| match aoc::repl::session(day, input) {
+                Ok(session) => aoc::repl::repl(day, session),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }
+            })
+        }
+        Some("run") => {
+            aoc::y2021::run(parse_day(args.get(1)));
+            Ok(())
+        }
+        Some("serve") => {
+            let port = match args.get(1).map(|port| port.parse()) {
+                Some(Ok(port)) => port,
+                Some(Err(_)) => {
+                    eprintln!("Expected a port number\n\n{}", USAGE);
+                    process::exit(1);
+                }
+                None => 2021,
+            };
+            aoc::serve::bind(port).and_then(|listener| {
+                println!(
+                    "Serving solvers on http://{}/<year>/<day>/part<n>",
+                    listener.local_addr()?
+                );
+                aoc::serve::serve(listener)
+            })
+        }
+        Some("status") => {
+            let days = aoc::status::collect();
+            println!("{}\n", aoc::status::render_terminal(&days));
+            println!("{}", aoc::status::render_markdown(&days));
+            Ok(())
+        }
+        #[cfg(target_os = "linux")]
+        Some("watch") => aoc::watch::watch(parse_day(args.get(1))),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
 }
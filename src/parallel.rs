@@ -0,0 +1,258 @@
+//! Grid operations spread over every available core, with the `parallel`
+//! feature. The cells are split into one contiguous chunk per scoped thread,
+//! and grids too small to pay for starting threads stay on the caller's.
+//!
+//! The threads are started for each call rather than kept in a pool, which
+//! keeps borrowing the grid safe without erasing lifetimes. Starting and
+//! joining a thread costs around 30µs, while a chunk is at least
+//! `MIN_CHUNK` cells, which takes a 3x3 window rule around 600µs, so the
+//! threads cost under 5% of the work they do.
+
+use crate::grid::{Grid, Pos};
+use std::ops::Range;
+use std::panic;
+use std::thread;
+
+/// The fewest cells worth handing to a thread of their own.
+const MIN_CHUNK: usize = 8192;
+
+/// How many threads to split `len` cells over.
+fn threads_for(len: usize) -> usize {
+    // Asking for the number of cores reads the system, so skip that when
+    // the cells wouldn't fill two threads anyway
+    if len < 2 * MIN_CHUNK {
+        return 1;
+    }
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    cores.min(len / MIN_CHUNK).max(1)
+}
+
+/// The length of each of the chunks splitting `len` cells over `threads`.
+fn chunk_size(len: usize, threads: usize) -> usize {
+    len.div_ceil(threads.max(1)).max(1)
+}
+
+/// Runs `work` on each chunk of `0..len`, returning the results in order of
+/// the chunks. Empty chunks are never run. A panic in `work` is passed on
+/// once every chunk has finished.
+pub fn chunked<R: Send>(len: usize, work: impl Fn(Range<usize>) -> R + Sync) -> Vec<R> {
+    chunked_over(threads_for(len), len, work)
+}
+
+fn chunked_over<R: Send>(
+    threads: usize,
+    len: usize,
+    work: impl Fn(Range<usize>) -> R + Sync,
+) -> Vec<R> {
+    let size = chunk_size(len, threads);
+    let chunks = (0..len)
+        .step_by(size)
+        .map(|start| start..len.min(start + size));
+    if threads <= 1 {
+        return chunks.map(work).collect();
+    }
+    let work = &work;
+    thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .map(|chunk| scope.spawn(move || work(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+/// Sets every one of `cells` to `f(index)`, in parallel.
+pub fn fill<T: Send>(cells: &mut [T], f: impl Fn(usize) -> T + Sync) {
+    fill_with(cells, || (), |_, index| f(index))
+}
+
+/// Like `fill`, but passing `f` scratch space made by `scratch()`, once for
+/// each chunk of cells.
+pub fn fill_with<T: Send, S>(
+    cells: &mut [T],
+    scratch: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, usize) -> T + Sync,
+) {
+    fill_over(threads_for(cells.len()), cells, scratch, f)
+}
+
+fn fill_over<T: Send, S>(
+    threads: usize,
+    cells: &mut [T],
+    scratch: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, usize) -> T + Sync,
+) {
+    let size = chunk_size(cells.len(), threads);
+    let fill_chunk = |start: usize, chunk: &mut [T]| {
+        let mut scratch = scratch();
+        chunk
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, cell)| *cell = f(&mut scratch, start + index))
+    };
+    if threads <= 1 {
+        return fill_chunk(0, cells);
+    }
+    let fill_chunk = &fill_chunk;
+    thread::scope(|scope| {
+        for (index, chunk) in cells.chunks_mut(size).enumerate() {
+            scope.spawn(move || fill_chunk(index * size, chunk));
+        }
+    });
+}
+
+/// The cells of a grid, to be visited in parallel.
+pub struct ParIter<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Sync> ParIter<'a, T> {
+    /// Folds each chunk of cells starting from `identity()`, then combines
+    /// the results of the chunks with `reduce`.
+    pub fn fold_reduce<A: Send>(
+        self,
+        identity: impl Fn() -> A + Sync,
+        fold: impl Fn(A, Pos, &T) -> A + Sync,
+        reduce: impl FnMut(A, A) -> A,
+    ) -> A {
+        let grid = self.grid;
+        chunked(grid.size(), |range| {
+            range.fold(identity(), |acc, index| {
+                fold(acc, grid.pos_of(index).unwrap(), &grid.elements()[index])
+            })
+        })
+        .into_iter()
+        .reduce(reduce)
+        .unwrap_or_else(identity)
+    }
+
+    /// Calls `f` on every cell, in no particular order.
+    pub fn for_each(self, f: impl Fn(Pos, &T) + Sync) {
+        self.fold_reduce(|| (), |_, pos, value| f(pos, value), |_, _| ())
+    }
+
+    /// The number of cells for which `predicate` holds.
+    pub fn count(self, predicate: impl Fn(Pos, &T) -> bool + Sync) -> usize {
+        self.fold_reduce(
+            || 0,
+            |count, pos, value| count + predicate(pos, value) as usize,
+            |a, b| a + b,
+        )
+    }
+}
+
+impl<T: Sync> Grid<T> {
+    pub fn par_iter(&self) -> ParIter<'_, T> {
+        ParIter { grid: self }
+    }
+
+    /// A grid of the same size with each cell mapped by `f`, like
+    /// `map_window` but a cell at a time and in parallel.
+    pub fn par_map<U: Send>(&self, f: impl Fn(Pos, &T) -> U + Sync) -> Grid<U> {
+        let elements = chunked(self.size(), |range| {
+            range
+                .map(|index| f(self.pos_of(index).unwrap(), &self.elements()[index]))
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+        Grid::new(elements, self.width())
+    }
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_chunked() {
+        let chunks = chunked_over(3, 1000, |range| range);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.first().unwrap().start, 0);
+        assert_eq!(chunks.last().unwrap().end, 1000);
+        assert!(chunks.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(chunked_over(3, 0, |range| range).is_empty());
+        // Small grids stay on the caller's thread
+        assert_eq!(chunked(MIN_CHUNK, |range| range), vec![0..MIN_CHUNK]);
+    }
+    #[test]
+    fn test_chunked_threads() {
+        let caller = thread::current().id();
+        let workers: HashSet<_> = chunked_over(3, 30, |_| thread::current().id())
+            .into_iter()
+            .collect();
+        assert!(!workers.contains(&caller));
+        assert_eq!(
+            chunked_over(1, 30, |_| thread::current().id()),
+            vec![caller]
+        );
+    }
+    #[test]
+    fn test_chunked_panic() {
+        let result = panic::catch_unwind(|| {
+            chunked_over(2, 4, |range| assert!(range.start == 0, "second chunk"))
+        });
+        assert!(result.is_err());
+    }
+    #[test]
+    fn test_fill() {
+        let mut cells = vec![0; 1000];
+        fill_over(3, &mut cells, || (), |_, index| index * 2);
+        assert!(cells
+            .iter()
+            .enumerate()
+            .all(|(index, &cell)| cell == index * 2));
+        // Each chunk gets scratch space of its own
+        let mut cells = vec![0; 9];
+        fill_over(
+            3,
+            &mut cells,
+            || 0,
+            |count, _| {
+                *count += 1;
+                *count
+            },
+        );
+        assert_eq!(cells, vec![1, 2, 3, 1, 2, 3, 1, 2, 3]);
+        let mut cells = vec![0; 10];
+        fill(&mut cells, |index| index + 1);
+        assert_eq!(cells, (1..=10).collect::<Vec<_>>());
+    }
+    #[test]
+    fn test_par_map() {
+        let grid = Grid::new((0..1000).collect(), 40);
+        let mapped = grid.par_map(|pos, &value| value * 2 + pos.y as usize);
+        assert_eq!(mapped.width(), 40);
+        assert_eq!(mapped[Pos::new(3, 2)], 2 * 83 + 2);
+        assert_eq!(
+            mapped.elements(),
+            &grid
+                .positions()
+                .map(|pos| grid[pos] * 2 + pos.y as usize)
+                .collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn test_par_iter() {
+        let grid = Grid::new((0..1000).collect(), 40);
+        assert_eq!(grid.par_iter().count(|_, &value| value % 3 == 0), 334);
+        let sum = grid
+            .par_iter()
+            .fold_reduce(|| 0, |sum, _, &value| sum + value, |a, b| a + b);
+        assert_eq!(sum, 499500);
+        let visited = AtomicUsize::new(0);
+        grid.par_iter().for_each(|_, _| {
+            visited.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!(visited.into_inner(), 1000);
+    }
+}
@@ -0,0 +1,393 @@
+use crate::y2021::{day11, day13, day18, day21};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// A day specific command, taking its (whitespace separated) arguments and
+/// returning a message to print before the new state.
+pub struct Command<T> {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub help: &'static str,
+    pub run: fn(&mut T, &[&str]) -> Result<String, String>,
+}
+
+/// Parsed puzzle state that can be explored from the REPL.
+pub trait Session {
+    fn show(&self) -> String;
+    fn reset(&mut self);
+    fn help(&self) -> String;
+    /// Runs a day specific command, or returns `None` if there is no such
+    /// command.
+    fn execute(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>>;
+}
+
+struct Explorer<T: 'static> {
+    initial: T,
+    state: T,
+    commands: &'static [Command<T>],
+}
+
+impl<T: Clone + fmt::Display> Explorer<T> {
+    fn boxed(state: T, commands: &'static [Command<T>]) -> Box<dyn Session> {
+        Box::new(Explorer {
+            initial: state.clone(),
+            state,
+            commands,
+        })
+    }
+}
+
+impl<T: Clone + fmt::Display> Session for Explorer<T> {
+    fn show(&self) -> String {
+        self.state.to_string()
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial.clone();
+    }
+
+    fn help(&self) -> String {
+        self.commands
+            .iter()
+            .map(|command| (command.usage, command.help))
+            .chain([
+                ("show", "Print the current state"),
+                ("reset", "Go back to the parsed input"),
+                ("help", "Print this help"),
+                ("quit", "Leave the REPL"),
+            ])
+            .map(|(usage, help)| format!("    {:<14} {}", usage, help))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn execute(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>> {
+        let command = self.commands.iter().find(|command| command.name == name)?;
+        Some((command.run)(&mut self.state, args))
+    }
+}
+
+/// Parses the optional repeat count taken by stepping commands.
+fn count(args: &[&str]) -> Result<usize, String> {
+    match args.first() {
+        Some(n) => n
+            .parse()
+            .map_err(|_| format!("Expected a count, got `{}`", n)),
+        None => Ok(1),
+    }
+}
+
+#[derive(Clone)]
+pub struct Octopi {
+    pub grid: day11::Grid,
+    pub steps: usize,
+    pub flashes: usize,
+}
+
+impl fmt::Display for Octopi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "After step {} ({} flashes)\n{}",
+            self.steps, self.flashes, self.grid
+        )
+    }
+}
+
+const OCTOPI: &[Command<Octopi>] = &[Command {
+    name: "step",
+    usage: "step [n]",
+    help: "Step the octopi n times",
+    run: |octopi, args| {
+        let n = count(args)?;
+        let flashes = (0..n).fold(0, |flashes, _| flashes + day11::step(&mut octopi.grid));
+        octopi.steps += n;
+        octopi.flashes += flashes;
+        Ok(format!("{} flashes", flashes))
+    },
+}];
+
+const PAPER: &[Command<day13::TransparentPaper>] = &[Command {
+    name: "fold",
+    usage: "fold [n]",
+    help: "Make the next n folds",
+    run: |paper, args| {
+        let n = count(args)?;
+        if n > paper.folds.len() {
+            return Err(format!("Only {} folds left", paper.folds.len()));
+        }
+        (0..n).for_each(|_| paper.fold());
+        Ok(format!(
+            "{} dots, {} folds left",
+            paper.dot_count(),
+            paper.folds.len()
+        ))
+    },
+}];
+
+/// A running sum of the homework numbers, adding the next number on request.
+#[derive(Clone)]
+pub struct Homework {
+    pub numbers: Vec<day18::Number>,
+    pub added: usize,
+    pub sum: day18::Number,
+}
+
+impl fmt::Display for Homework {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\n(added {} of {} numbers)",
+            self.sum,
+            self.added,
+            self.numbers.len()
+        )
+    }
+}
+
+const HOMEWORK: &[Command<Homework>] = &[
+    Command {
+        name: "add",
+        usage: "add",
+        help: "Add the next number without reducing",
+        run: |homework, _| {
+            let next = homework
+                .numbers
+                .get(homework.added)
+                .ok_or("No numbers left to add")?;
+            homework.sum = homework.sum.clone() + next.clone();
+            homework.added += 1;
+            Ok(format!("Added {}", next))
+        },
+    },
+    Command {
+        name: "explode",
+        usage: "explode",
+        help: "Explode the leftmost pair nested inside four pairs",
+        run: |homework, _| match homework.sum.try_explode(0) {
+            Some(_) => Ok(String::from("Exploded")),
+            None => Err(String::from("Nothing to explode")),
+        },
+    },
+    Command {
+        name: "split",
+        usage: "split",
+        help: "Split the leftmost number of 10 or more",
+        run: |homework, _| match homework.sum.try_split() {
+            true => Ok(String::from("Split")),
+            false => Err(String::from("Nothing to split")),
+        },
+    },
+    Command {
+        name: "reduce",
+        usage: "reduce",
+        help: "Explode and split until the sum is reduced",
+        run: |homework, _| {
+            homework.sum.reduce();
+            Ok(format!("Magnitude {}", homework.sum.magnitude()))
+        },
+    },
+];
+
+const GAME: &[Command<day21::Game>] = &[Command {
+    name: "turn",
+    usage: "turn [n]",
+    help: "Play n turns with the deterministic die",
+    run: |game, args| {
+        let n = count(args)?;
+        if game.player_one.1 >= 1000 || game.player_two.1 >= 1000 {
+            return Err(String::from("The game is over"));
+        }
+        match (1..=n).find(|_| game.det_turn()) {
+            Some(_) => Ok(format!("Player {} wins", if game.one_next { 2 } else { 1 })),
+            None => Ok(format!("Played {} turns", n)),
+        }
+    },
+}];
+
+/// Parses a day's input into a session.
+type Constructor = fn(String) -> Result<Box<dyn Session>, String>;
+
+/// The days with REPL commands, and how to start a session for each.
+const SESSIONS: &[(u8, Constructor)] = &[
+    (11, |input| {
+        Ok(Explorer::boxed(
+            Octopi {
+                grid: day11::parse_input(input),
+                steps: 0,
+                flashes: 0,
+            },
+            OCTOPI,
+        ))
+    }),
+    (13, |input| {
+        Ok(Explorer::boxed(day13::parse_input(input), PAPER))
+    }),
+    (18, |input| {
+        let numbers = day18::parse_input(input);
+        let first = numbers
+            .first()
+            .ok_or("Day 18 needs at least one number to add up")?;
+        Ok(Explorer::boxed(
+            Homework {
+                sum: first.clone(),
+                added: 1,
+                numbers,
+            },
+            HOMEWORK,
+        ))
+    }),
+    (21, |input| {
+        Ok(Explorer::boxed(day21::parse_input(input), GAME))
+    }),
+];
+
+fn constructor(day: u8) -> Option<Constructor> {
+    SESSIONS
+        .iter()
+        .find(|&&(session_day, _)| session_day == day)
+        .map(|&(_, constructor)| constructor)
+}
+
+/// Whether `day` has REPL commands, to check before fetching its input.
+pub fn has_session(day: u8) -> bool {
+    constructor(day).is_some()
+}
+
+/// Parses the input for a day that has REPL commands.
+pub fn session(day: u8, input: String) -> Result<Box<dyn Session>, String> {
+    match constructor(day) {
+        Some(constructor) => constructor(input),
+        None => Err(format!("Day {} has no REPL commands", day)),
+    }
+}
+
+pub enum Eval {
+    Print(String),
+    Quit,
+}
+
+/// Evaluates one line of REPL input against a session.
+pub fn eval(session: &mut dyn Session, line: &str) -> Eval {
+    let mut words = line.split_whitespace();
+    let name = match words.next() {
+        Some(name) => name,
+        None => return Eval::Print(String::new()),
+    };
+    let args: Vec<&str> = words.collect();
+    Eval::Print(match name {
+        "show" => session.show(),
+        "reset" => {
+            session.reset();
+            session.show()
+        }
+        "help" => session.help(),
+        "quit" | "exit" => return Eval::Quit,
+        _ => match session.execute(name, &args) {
+            Some(Ok(message)) => format!("{}\n{}", message, session.show()),
+            Some(Err(err)) => err,
+            None => format!("Unknown command `{}`, try `help`", name),
+        },
+    })
+}
+
+/// Reads commands from stdin until `quit` or end of input.
+pub fn repl(day: u8, mut session: Box<dyn Session>) -> io::Result<()> {
+    println!("{}\n\nCommands:\n{}", session.show(), session.help());
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("day{}> ", day);
+        io::stdout().flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(()),
+        };
+        match eval(session.as_mut(), &line) {
+            Eval::Print(output) if output.is_empty() => {}
+            Eval::Print(output) => println!("{}", output),
+            Eval::Quit => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod repl_tests {
+    use super::*;
+
+    fn output(session: &mut dyn Session, line: &str) -> String {
+        match eval(session, line) {
+            Eval::Print(output) => output,
+            Eval::Quit => panic!("unexpected quit"),
+        }
+    }
+
+    #[test]
+    fn test_octopi() {
+        let mut session = session(11, String::from("11111\n19991\n19191\n19991\n11111")).unwrap();
+        assert_eq!(
+            output(session.as_mut(), "step"),
+            "9 flashes\nAfter step 1 (9 flashes)\n34543\n4\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m4\n5\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m5\n4\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m\x1b[93m0\x1b[0m4\n34543"
+        );
+        assert!(output(session.as_mut(), "step 2").starts_with("0 flashes\nAfter step 3"));
+        assert!(output(session.as_mut(), "reset").starts_with("After step 0 (0 flashes)"));
+        assert_eq!(
+            output(session.as_mut(), "step x"),
+            "Expected a count, got `x`"
+        );
+    }
+    #[test]
+    fn test_paper() {
+        let dots = "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0";
+        let mut session =
+            session(13, format!("{}\n\nfold along y=7\nfold along x=5", dots)).unwrap();
+        assert!(output(session.as_mut(), "fold").starts_with("17 dots, 1 folds left\n"));
+        assert_eq!(output(session.as_mut(), "fold 2"), "Only 1 folds left");
+        assert!(output(session.as_mut(), "fold").starts_with("16 dots, 0 folds left\n"));
+        output(session.as_mut(), "reset");
+        assert!(output(session.as_mut(), "fold 2").starts_with("16 dots, 0 folds left\n"));
+    }
+    #[test]
+    fn test_homework() {
+        let mut session =
+            session(18, String::from("[[[[4,3],4],4],[7,[[8,4],9]]]\n[1,1]")).unwrap();
+        assert!(output(session.as_mut(), "add")
+            .ends_with("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]\n(added 2 of 2 numbers)"));
+        assert!(output(session.as_mut(), "explode").contains("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]"));
+        assert!(output(session.as_mut(), "reduce").contains("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+        assert_eq!(output(session.as_mut(), "split"), "Nothing to split");
+        assert_eq!(output(session.as_mut(), "add"), "No numbers left to add");
+    }
+    #[test]
+    fn test_game() {
+        let mut session = session(
+            21,
+            String::from("Player 1 starting position: 4\nPlayer 2 starting position: 8"),
+        )
+        .unwrap();
+        assert!(output(session.as_mut(), "turn 2")
+            .starts_with("Played 2 turns\nPlayer 1 at 10 with 10\nPlayer 2 at 3 with 3\nRolls: 6"));
+        assert!(output(session.as_mut(), "turn 1000").starts_with("Player 1 wins"));
+        assert_eq!(output(session.as_mut(), "turn"), "The game is over");
+    }
+    #[test]
+    fn test_builtins() {
+        let mut session = session(21, String::from("p 1\np 2")).unwrap();
+        assert_eq!(
+            output(session.as_mut(), "jump"),
+            "Unknown command `jump`, try `help`"
+        );
+        assert!(output(session.as_mut(), "help").contains("turn [n]"));
+        assert!(matches!(eval(session.as_mut(), "quit"), Eval::Quit));
+        assert!(!has_session(1));
+        assert!(has_session(18));
+        assert_eq!(
+            super::session(1, String::new()).err(),
+            Some(String::from("Day 1 has no REPL commands"))
+        );
+        assert_eq!(
+            super::session(18, String::new()).err(),
+            Some(String::from("Day 18 needs at least one number to add up"))
+        );
+    }
+}
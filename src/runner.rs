@@ -0,0 +1,202 @@
+use crate::y2021::{solver, SOLVERS};
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::time::{Duration, Instant};
+
+/// Path to the source file of a day's solution.
+pub fn source_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("src/y2021/day{:02}.rs", day))
+}
+
+/// Days which have a registered solver.
+pub fn registered_days() -> Vec<u8> {
+    SOLVERS.iter().map(|solver| solver.day).collect()
+}
+
+/// Path to a day's cached puzzle input.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("input/day{}.txt", day))
+}
+
+pub fn format_time(time: Duration) -> String {
+    format!("{:.2}s", time.as_secs_f64())
+}
+
+fn cargo(args: &[&str]) -> (Output, Duration) {
+    let start = Instant::now();
+    let output = Command::new("cargo")
+        .args(args)
+        .output()
+        .expect("Unable to run cargo");
+    (output, start.elapsed())
+}
+
+/// Builds a day's binary, returning how long the build took or cargo's error
+/// output if it failed.
+pub fn build(day: u8) -> Result<Duration, String> {
+    let bin = format!("day{}", day);
+    let (output, time) = cargo(&["build", "--quiet", "--bin", &bin]);
+    if output.status.success() {
+        Ok(time)
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestSummary {
+    pub passed: Vec<String>,
+    pub failed: Vec<String>,
+    pub success: bool,
+    pub time: Duration,
+}
+
+impl TestSummary {
+    pub fn ok(&self) -> bool {
+        self.success && self.failed.is_empty()
+    }
+
+    pub fn passed(&self, test: &str) -> bool {
+        self.passed.iter().any(|name| name.ends_with(test))
+    }
+}
+
+/// Runs a day's tests, skipping the `solution_*` tests (which need the puzzle
+/// input) unless `include_solutions` is set.
+pub fn tests(day: u8, include_solutions: bool) -> TestSummary {
+    let module = format!("y2021::day{:02}::", day);
+    let mut args = vec!["test", "--lib", "--", &module];
+    if !include_solutions {
+        args.extend(["--skip", "::solution_"]);
+    }
+    let (output, time) = cargo(&args);
+    let mut summary = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+    summary.success = output.status.success();
+    summary.time = time;
+    summary
+}
+
+/// Runs a day's example tests, i.e. those which don't need the puzzle input.
+pub fn examples(day: u8) -> TestSummary {
+    tests(day, false)
+}
+
+fn parse_test_output(stdout: &str) -> TestSummary {
+    let mut summary = TestSummary {
+        passed: Vec::new(),
+        failed: Vec::new(),
+        success: true,
+        time: Duration::ZERO,
+    };
+    stdout.lines().for_each(|line| {
+        if let Some(test) = line.strip_prefix("test ") {
+            if let Some(name) = test.strip_suffix(" ... ok") {
+                summary.passed.push(name.to_string());
+            } else if let Some(name) = test.strip_suffix(" ... FAILED") {
+                summary.failed.push(name.to_string());
+            }
+        }
+    });
+    summary
+}
+
+/// Runs a day's binary against its puzzle input, returning the answers it
+/// printed and how long it took.
+pub fn parts(day: u8) -> Result<(Vec<String>, Duration), String> {
+    let bin = format!("day{}", day);
+    let (output, time) = cargo(&["run", "--quiet", "--bin", &bin]);
+    if output.status.success() {
+        Ok((parse_parts(&String::from_utf8_lossy(&output.stdout)), time))
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).into())
+    }
+}
+
+/// Solves both parts of a registered day against its cached input in this
+/// process, returning the answers and how long the solvers took, without
+/// the time to read the input. Fails if there is no solver or input, or if
+/// a part panics.
+pub fn solve(day: u8) -> Result<(Vec<String>, Duration), String> {
+    let solver = solver(day).ok_or_else(|| format!("Day {} is not registered", day))?;
+    let input = fs::read_to_string(input_path(day))
+        .map_err(|err| format!("Unable to read the input for day {}: {}", day, err))?;
+    let mut answers = Vec::new();
+    let mut time = Duration::ZERO;
+    for part in 1..=2 {
+        let solve = solver.part(part).unwrap();
+        let input = input.clone();
+        let start = Instant::now();
+        let answer = panic::catch_unwind(|| solve(input))
+            .map_err(|_| format!("Part {} of day {} panicked", part, day))?;
+        time += start.elapsed();
+        answers.push(answer);
+    }
+    Ok((answers, time))
+}
+
+fn parse_parts(stdout: &str) -> Vec<String> {
+    let mut lines = stdout.lines().peekable();
+    let mut parts = Vec::new();
+    while let Some(line) = lines.next() {
+        if let Some(answer) = line.strip_prefix("Part ").and_then(|l| l.split_once(": ")) {
+            let answer = answer.1.trim();
+            if answer.is_empty() {
+                // Multi-line answers (e.g. letters drawn in a grid) start on
+                // the next line
+                let rows =
+                    std::iter::from_fn(|| lines.next_if(|line| !line.starts_with("Part "))).count();
+                parts.push(format!("<{} lines>", rows));
+            } else {
+                parts.push(answer.to_string());
+            }
+        }
+    }
+    parts
+}
+
+#[cfg(test)]
+mod runner_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_output() {
+        let stdout = "
+running 2 tests
+test y2021::day11::day_11_tests::test_part_1 ... ok
+test y2021::day11::day_11_tests::test_part_2 ... FAILED
+
+failures:
+";
+        let summary = parse_test_output(stdout);
+        assert_eq!(
+            summary.passed,
+            vec!["y2021::day11::day_11_tests::test_part_1"]
+        );
+        assert_eq!(
+            summary.failed,
+            vec!["y2021::day11::day_11_tests::test_part_2"]
+        );
+        assert!(summary.passed("test_part_1"));
+        assert!(!summary.passed("test_part_2"));
+    }
+
+    #[test]
+    fn test_solve_unregistered() {
+        assert_eq!(solve(25), Err(String::from("Day 25 is not registered")));
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        let stdout = "==== Day 13 ====
+Input size: 100
+Part 1: 17
+Part 2: 
+#####
+#...#
+#####
+";
+        assert_eq!(parse_parts(stdout), vec!["17", "<3 lines>"]);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::y2021::solver;
+use crate::YEAR;
+use std::any::Any;
+use std::io::{self, BufRead, BufReader, Read, Take, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::panic;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The largest request body accepted, well above any puzzle input.
+const MAX_BODY: u64 = 1 << 20;
+
+/// The largest request line and headers accepted, together.
+const MAX_HEAD: u64 = 16 << 10;
+
+/// How long a read may wait on the client, so that one sending less than it
+/// claimed doesn't hold its thread forever.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Binds the solver service to a port on localhost (0 picks a free port).
+pub fn bind(port: u16) -> io::Result<TcpListener> {
+    TcpListener::bind(("127.0.0.1", port))
+}
+
+/// Serves `POST /<year>/<day>/part<n>` requests, answering the puzzle input in
+/// the request body with JSON.
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(err) = handle(stream) {
+                eprintln!("Error handling request: {}", err);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    // The request line and headers share one limit, so that neither an
+    // endless line nor endless headers can use up memory
+    let mut head = reader.by_ref().take(MAX_HEAD);
+    let too_large = format!("Expected headers of at most {} bytes", MAX_HEAD);
+    let request_line = match read_line(&mut head)? {
+        Some(line) => line,
+        None => return respond(stream, 431, &error(&too_large)),
+    };
+    let mut request = request_line.split_whitespace();
+    let method = request.next().unwrap_or_default().to_string();
+    let path = request.next().unwrap_or_default().to_string();
+
+    let mut content_length: u64 = 0;
+    let mut expect_continue = false;
+    loop {
+        let header = match read_line(&mut head)? {
+            Some(header) => header,
+            None => return respond(stream, 431, &error(&too_large)),
+        };
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = match value.trim().parse() {
+                    Ok(length) => length,
+                    Err(_) => {
+                        let message = format!("Invalid Content-Length `{}`", value.trim());
+                        return respond(stream, 400, &error(&message));
+                    }
+                };
+            } else if name.eq_ignore_ascii_case("expect") {
+                expect_continue = value.trim().eq_ignore_ascii_case("100-continue");
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        let message = format!("Expected a body of at most {} bytes", MAX_BODY);
+        return respond(stream, 413, &error(&message));
+    }
+    // Clients like curl wait for this before sending a large body
+    if expect_continue {
+        stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+    }
+    // Read no more than was claimed, however much the client sends
+    let mut body = Vec::new();
+    reader.take(content_length).read_to_end(&mut body)?;
+
+    let (status, json) = route(&method, &path, String::from_utf8_lossy(&body).into());
+    respond(stream, status, &json)
+}
+
+/// Reads a line from the request head, or `None` if it runs past the limit
+/// on the head. An empty line means the client closed the connection.
+fn read_line(head: &mut Take<impl BufRead>) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    head.read_line(&mut line)?;
+    if !line.ends_with('\n') && head.limit() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+fn respond(mut stream: TcpStream, status: u16, json: &str) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        json.len(),
+        json
+    )?;
+    stream.flush()?;
+    // Closing with unread input would reset the connection, which can lose
+    // the response before the client reads it. So stop writing, and read
+    // whatever is left of the request (up to a limit) until the client closes
+    stream.shutdown(Shutdown::Write)?;
+    let _ = io::copy(&mut stream.take(MAX_BODY + MAX_HEAD), &mut io::sink());
+    Ok(())
+}
+
+fn error(message: &str) -> String {
+    format!("{{\"error\":{}}}", json_string(message))
+}
+
+fn route(method: &str, path: &str, body: String) -> (u16, String) {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments[..] {
+        [year, day, part] => (
+            year.parse::<u16>().ok(),
+            day.parse::<u8>().ok(),
+            part.strip_prefix("part")
+                .and_then(|part| part.parse::<u8>().ok()),
+        ),
+        _ => return (404, error("Expected /<year>/<day>/part<n>")),
+    };
+    let (day, part) = match (year, day, part) {
+        (Some(year), Some(day), Some(part)) if year == YEAR => (day, part),
+        _ => return (404, error("Expected /<year>/<day>/part<n>")),
+    };
+    let solve = match solver(day).and_then(|solver| solver.part(part)) {
+        Some(solve) => solve,
+        None => return (404, error(&format!("No solver for {} day {}", YEAR, day))),
+    };
+    if method != "POST" {
+        return (
+            405,
+            error("Expected POST with the puzzle input as the body"),
+        );
+    }
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| solve(body));
+    let time = start.elapsed();
+    let (status, answer, error) = match result {
+        Ok(answer) => (200, json_string(&answer), "null".to_string()),
+        Err(err) => (422, "null".to_string(), json_string(&panic_message(err))),
+    };
+    (
+        status,
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"time_ms\":{:.3},\"error\":{}}}",
+            YEAR,
+            day,
+            part,
+            answer,
+            time.as_secs_f64() * 1000.0,
+            error
+        ),
+    )
+}
+
+/// Solvers report parse errors by panicking (mostly through `unwrap`).
+fn panic_message(err: Box<dyn Any + Send>) -> String {
+    if let Some(message) = err.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = err.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Solver panicked")
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    s.chars().for_each(|c| match c {
+        '"' => json += "\\\"",
+        '\\' => json += "\\\\",
+        '\n' => json += "\\n",
+        '\r' => json += "\\r",
+        '\t' => json += "\\t",
+        c if (c as u32) < 0x20 => json += &format!("\\u{:04x}", c as u32),
+        c => json.push(c),
+    });
+    json + "\""
+}
+
+#[cfg(test)]
+mod serve_tests {
+    use super::*;
+
+    fn send(raw: &str) -> String {
+        let listener = bind(0).unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    fn request(method: &str, path: &str, body: &str) -> String {
+        send(&format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        ))
+    }
+
+    #[test]
+    fn test_solve() {
+        let response = request("POST", "/2021/6/part1", "3,4,3,1,2");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("{\"year\":2021,\"day\":6,\"part\":1,\"answer\":\"5934\","));
+        assert!(response.ends_with(",\"error\":null}"));
+    }
+    #[test]
+    fn test_parse_error() {
+        let response = request("POST", "/2021/16/part2", "not hex");
+        assert!(response.starts_with("HTTP/1.1 422 "));
+        assert!(response.contains("\"answer\":null"));
+        assert!(!response.contains("\"error\":null"));
+    }
+    #[test]
+    fn test_not_found() {
+        assert!(request("POST", "/2021/26/part1", "").starts_with("HTTP/1.1 404 "));
+        assert!(request("POST", "/2020/6/part1", "").starts_with("HTTP/1.1 404 "));
+        assert!(request("POST", "/2021/6/part3", "").starts_with("HTTP/1.1 404 "));
+        assert!(request("GET", "/2021/6/part1", "").starts_with("HTTP/1.1 405 "));
+    }
+    #[test]
+    fn test_too_large() {
+        let response =
+            send("POST /2021/6/part1 HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\n3,4");
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+    }
+    #[test]
+    fn test_bad_length() {
+        let response = send("POST /2021/6/part1 HTTP/1.1\r\nContent-Length: three\r\n\r\n3,4");
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+        assert!(response.ends_with("{\"error\":\"Invalid Content-Length `three`\"}"));
+    }
+    #[test]
+    fn test_long_headers() {
+        let header = format!("X-Padding: {}\r\n", "a".repeat(1000));
+        let response = send(&format!(
+            "POST /2021/6/part1 HTTP/1.1\r\n{}Content-Length: 3\r\n\r\n3,4",
+            header.repeat(20)
+        ));
+        assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large\r\n"));
+        let line = format!("POST /{} HTTP/1.1\r\n\r\n", "a".repeat(20000));
+        assert!(send(&line).starts_with("HTTP/1.1 431 "));
+    }
+    #[test]
+    fn test_expect_continue() {
+        let response = send(
+            "POST /2021/6/part1 HTTP/1.1\r\nExpect: 100-continue\r\nContent-Length: 9\r\n\r\n3,4,3,1,2",
+        );
+        assert!(response.starts_with("HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\"answer\":\"5934\""));
+    }
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::runner::{self, format_time};
+use crate::YEAR;
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub registered: bool,
+    pub input: bool,
+    /// Stars are earned by the `solution_part_*` tests, which assert the
+    /// accepted answers and so double as the answer ledger
+    pub stars: u8,
+    pub failing: bool,
+    pub runtime: Option<Duration>,
+}
+
+impl DayStatus {
+    fn mark(&self) -> &'static str {
+        if self.failing {
+            "!!"
+        } else if !self.registered && self.input {
+            "??"
+        } else {
+            match self.stars {
+                2 => "**",
+                1 => "* ",
+                _ => "  ",
+            }
+        }
+    }
+
+    fn markdown(&self) -> String {
+        if self.failing {
+            format!("{} ❌", self.day)
+        } else if !self.registered && self.input {
+            format!("{} ❔", self.day)
+        } else {
+            format!("{} {}", self.day, "⭐".repeat(self.stars as usize))
+                .trim_end()
+                .to_string()
+        }
+    }
+}
+
+/// Checks every day of the calendar: whether it has a solver and a cached
+/// input, runs its tests to find its stars and failures, and times its parts
+/// in this process.
+pub fn collect() -> Vec<DayStatus> {
+    let registered = runner::registered_days();
+    let days = (1..=25)
+        .map(|day| {
+            let mut status = DayStatus {
+                day,
+                registered: registered.contains(&day),
+                input: runner::input_path(day).exists(),
+                ..Default::default()
+            };
+            if status.registered {
+                eprint!("\rChecking day {}...", day);
+                // Without a cached input only the examples can be run
+                let tests = if status.input {
+                    runner::tests(day, true)
+                } else {
+                    runner::examples(day)
+                };
+                status.failing = tests
+                    .failed
+                    .iter()
+                    .any(|test| !test.contains("::solution_"))
+                    || (!tests.success && tests.passed.is_empty());
+                status.stars = ["solution_part_1", "solution_part_2"]
+                    .iter()
+                    .filter(|test| tests.passed(test))
+                    .count() as u8;
+                if status.input {
+                    status.runtime = runner::solve(day).ok().map(|(_, time)| time);
+                }
+            }
+            status
+        })
+        .collect();
+    eprint!("\r{:20}\r", "");
+    days
+}
+
+fn total_stars(days: &[DayStatus]) -> usize {
+    days.iter().map(|day| day.stars as usize).sum()
+}
+
+fn total_runtime(days: &[DayStatus]) -> Duration {
+    days.iter().filter_map(|day| day.runtime).sum()
+}
+
+/// Renders the calendar as a grid of days for the terminal.
+pub fn render_terminal(days: &[DayStatus]) -> String {
+    let mut out = format!("Advent of Code {}\n", YEAR);
+    days.chunks(5).for_each(|week| {
+        let row: Vec<String> = week
+            .iter()
+            .map(|day| format!("{:>2} {}", day.day, day.mark()))
+            .collect();
+        out += &row.join("  ");
+        out += "\n";
+    });
+    out += &format!(
+        "\nStars: {}/50  Failing: {}  Unsolved inputs: {}  Runtime: {}\n",
+        total_stars(days),
+        days.iter().filter(|day| day.failing).count(),
+        days.iter()
+            .filter(|day| !day.registered && day.input)
+            .count(),
+        format_time(total_runtime(days))
+    );
+    out += "** both stars, * one star, !! failing examples, ?? input without a solution";
+    out
+}
+
+/// Renders the calendar as a Markdown table suitable for a README.
+pub fn render_markdown(days: &[DayStatus]) -> String {
+    let mut out = format!("### Advent of Code {}\n\n", YEAR);
+    out += "|   |   |   |   |   |\n|---|---|---|---|---|\n";
+    days.chunks(5).for_each(|week| {
+        let row: Vec<String> = week.iter().map(|day| day.markdown()).collect();
+        out += &format!("| {} |\n", row.join(" | "));
+    });
+    out += &format!(
+        "\n**{}/50** stars, total runtime {}",
+        total_stars(days),
+        format_time(total_runtime(days))
+    );
+    out
+}
+
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+
+    fn calendar() -> Vec<DayStatus> {
+        (1..=25)
+            .map(|day| DayStatus {
+                day,
+                registered: day <= 3,
+                input: day <= 4,
+                stars: if day <= 2 { 2 } else { 0 },
+                failing: day == 3,
+                runtime: Some(Duration::from_millis(250)).filter(|_| day <= 2),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_render_terminal() {
+        let rendered = render_terminal(&calendar());
+        let mut lines = rendered.lines().skip(1);
+        assert_eq!(lines.next().unwrap(), " 1 **   2 **   3 !!   4 ??   5   ");
+        assert!(rendered.contains("Stars: 4/50  Failing: 1  Unsolved inputs: 1  Runtime: 0.50s"));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let rendered = render_markdown(&calendar());
+        assert!(rendered.contains("| 1 ⭐⭐ | 2 ⭐⭐ | 3 ❌ | 4 ❔ | 5 |\n"));
+        assert!(rendered.ends_with("**4/50** stars, total runtime 0.50s"));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::runner::{self, format_time};
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind, Read};
+use std::os::raw::{c_char, c_int};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::FromRawFd;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+const IN_CLOSE_WRITE: u32 = 0x0000_0008;
+const IN_MOVED_TO: u32 = 0x0000_0080;
+const IN_CREATE: u32 = 0x0000_0100;
+const IN_NONBLOCK: c_int = 0o4000;
+const IN_CLOEXEC: c_int = 0o2000000;
+
+extern "C" {
+    fn inotify_init1(flags: c_int) -> c_int;
+    fn inotify_add_watch(fd: c_int, pathname: *const c_char, mask: u32) -> c_int;
+}
+
+/// A non-blocking inotify instance watching directories for files being
+/// written, created or moved into them.
+struct Inotify {
+    file: File,
+    watches: Vec<(i32, PathBuf)>,
+}
+
+impl Inotify {
+    fn new() -> io::Result<Self> {
+        let fd = unsafe { inotify_init1(IN_NONBLOCK | IN_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Inotify {
+            file: unsafe { File::from_raw_fd(fd) },
+            watches: Vec::new(),
+        })
+    }
+
+    fn add_watch(&mut self, dir: &Path) -> io::Result<()> {
+        use std::os::unix::io::AsRawFd;
+
+        let path = CString::new(dir.as_os_str().as_bytes())?;
+        let mask = IN_CLOSE_WRITE | IN_MOVED_TO | IN_CREATE;
+        let wd = unsafe { inotify_add_watch(self.file.as_raw_fd(), path.as_ptr(), mask) };
+        if wd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        self.watches.push((wd, dir.to_path_buf()));
+        Ok(())
+    }
+
+    /// Reads all pending events, returning the paths they refer to.
+    fn read_events(&mut self) -> io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        let mut buffer = [0; 4096];
+        loop {
+            let len = match self.file.read(&mut buffer) {
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(paths),
+                Err(e) => return Err(e),
+            };
+            // Each event is a 16 byte header (wd, mask, cookie, len) followed
+            // by a nul-padded name of len bytes
+            let mut offset = 0;
+            while offset + 16 <= len {
+                let field = |n: usize| {
+                    let start = offset + 4 * n;
+                    [
+                        buffer[start],
+                        buffer[start + 1],
+                        buffer[start + 2],
+                        buffer[start + 3],
+                    ]
+                };
+                let wd = i32::from_ne_bytes(field(0));
+                let name_len = u32::from_ne_bytes(field(3)) as usize;
+                let name = &buffer[offset + 16..offset + 16 + name_len];
+                let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name_len)];
+                if let Some((_, dir)) = self.watches.iter().find(|(w, _)| *w == wd) {
+                    paths.push(dir.join(std::ffi::OsStr::from_bytes(name)));
+                }
+                offset += 16 + name_len;
+            }
+        }
+    }
+}
+
+/// Files to watch for a day: its source, its input and any example fixtures
+/// saved next to the input as `input/dayN.*`.
+fn is_watched(day: u8, path: &Path) -> bool {
+    let name = match path.file_name() {
+        Some(name) => name.to_string_lossy(),
+        None => return false,
+    };
+    path == runner::source_path(day)
+        || (path.parent() == runner::input_path(day).parent()
+            && name.starts_with(&format!("day{}.", day)))
+}
+
+/// Rebuilds a day and runs its examples and parts, printing a one line
+/// summary (plus any errors).
+fn run_day(day: u8) {
+    print!("day {} | ", day);
+    match runner::build(day) {
+        Ok(time) => print!("build {} | ", format_time(time)),
+        Err(err) => {
+            println!("build FAILED\n{}", err);
+            return;
+        }
+    }
+
+    let examples = runner::examples(day);
+    print!(
+        "examples {}/{} {} {}",
+        examples.passed.len(),
+        examples.passed.len() + examples.failed.len(),
+        if examples.ok() { "ok" } else { "FAILED" },
+        format_time(examples.time)
+    );
+
+    if runner::input_path(day).exists() {
+        match runner::parts(day) {
+            Ok((parts, time)) => {
+                parts
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, answer)| print!(" | part {}: {}", i + 1, answer));
+                println!(" | {}", format_time(time));
+            }
+            Err(err) => println!(" | parts FAILED\n{}", err),
+        }
+    } else {
+        println!(" | no input");
+    }
+    examples
+        .failed
+        .iter()
+        .for_each(|test| println!("  failed: {}", test));
+}
+
+/// Watches a day's source, input and example fixtures, re-running the day
+/// whenever one of them is saved.
+pub fn watch(day: u8) -> io::Result<()> {
+    let source = runner::source_path(day);
+    if !source.exists() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!("{} does not exist", source.display()),
+        ));
+    }
+
+    // The input directory may not exist yet in a fresh checkout, and an
+    // input fetched into it later must still trigger a run
+    let input = runner::input_path(day);
+    if let Some(dir) = input.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut inotify = Inotify::new()?;
+    for path in [source, input] {
+        if let Some(dir) = path.parent() {
+            inotify.add_watch(dir)?;
+        }
+    }
+
+    println!("Watching day {} (ctrl-c to stop)", day);
+    run_day(day);
+    loop {
+        // Poll rather than block so that the burst of events from a single
+        // save results in a single run
+        thread::sleep(Duration::from_millis(200));
+        let changed = inotify.read_events()?;
+        if changed.iter().any(|path| is_watched(day, path)) {
+            run_day(day);
+        }
+    }
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use super::*;
+
+    #[test]
+    fn test_is_watched() {
+        assert!(is_watched(11, Path::new("src/y2021/day11.rs")));
+        assert!(is_watched(11, Path::new("input/day11.txt")));
+        assert!(is_watched(11, Path::new("input/day11.example.txt")));
+        assert!(!is_watched(11, Path::new("input/day1.txt")));
+        assert!(!is_watched(11, Path::new("src/y2021/day01.rs")));
+        assert!(!is_watched(1, Path::new("input/day11.txt")));
+    }
+
+    #[test]
+    fn test_inotify_events() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut inotify = Inotify::new().unwrap();
+        inotify.add_watch(&dir).unwrap();
+        std::fs::write(dir.join("day3.txt"), "input").unwrap();
+        let events = inotify.read_events().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(events.contains(&dir.join("day3.txt")));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::get_input;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+
+/// A registered day: its parts take the raw puzzle input and return the
+/// answer as a string.
+pub struct Solver {
+    pub day: u8,
+    pub part_1: fn(String) -> String,
+    pub part_2: fn(String) -> String,
+}
+
+impl Solver {
+    pub fn part(&self, part: u8) -> Option<fn(String) -> String> {
+        match part {
+            1 => Some(self.part_1),
+            2 => Some(self.part_2),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! solvers {
+    ($($day:literal => $module:ident,)*) => {
+        pub const SOLVERS: &[Solver] = &[$(
+            Solver {
+                day: $day,
+                part_1: |input| $module::part_1(&$module::parse_input(input)).to_string(),
+                part_2: |input| $module::part_2(&$module::parse_input(input)).to_string(),
+            },
+        )*];
+    };
+}
+
+solvers! {
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20,
+    21 => day21,
+    22 => day22,
+}
+
+pub fn solver(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day)
+}
+
+/// Solves both parts of a day against its puzzle input, printing the answers.
+pub fn run(day: u8) {
+    let solver = solver(day).unwrap_or_else(|| panic!("Day {} is not registered", day));
+    println!("==== Day {} ====", day);
+    let input = get_input(day);
+    println!("Input size: {}", input.len());
+    for part in 1..=2 {
+        let answer = solver.part(part).unwrap()(input.clone());
+        // Start multi-line answers on their own line
+        let separator = if answer.contains('\n') { "\n" } else { "" };
+        println!("Part {}: {}{}", part, separator, answer);
+    }
+}
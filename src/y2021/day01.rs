@@ -0,0 +1,46 @@
+pub fn parse_input(input: String) -> Vec<usize> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn part_1(input: &[usize]) -> usize {
+    input
+        .iter()
+        .zip(input.iter().skip(1))
+        .filter(|(prev, curr)| curr > prev)
+        .count()
+}
+
+pub fn part_2(input: &[usize]) -> usize {
+    let windows: Vec<usize> = input.windows(3).map(|w| w.iter().sum()).collect();
+    part_1(&windows)
+}
+
+#[cfg(test)]
+mod day_1_tests {
+    use super::*;
+    use crate::get_input;
+    #[test]
+    fn test_part_1() {
+        let input = String::from("199\n200\n208\n210\n200\n207\n240\n269\n260\n263");
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 7);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(1);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 1688);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from("199\n200\n208\n210\n200\n207\n240\n269\n260\n263");
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 5);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(1);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 1728);
+    }
+}
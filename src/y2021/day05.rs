@@ -0,0 +1,140 @@
+use crate::geom::{count_overlaps, Segment};
+use crate::grid::Pos;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const DAY: u8 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    pub fn is_diag(&self) -> bool {
+        self.start.x != self.end.x && self.start.y != self.end.y
+    }
+
+    pub fn get_points_along(&self) -> Vec<Point> {
+        Segment::from(*self)
+            .bresenham()
+            .into_iter()
+            .map(|pos| Point {
+                x: pos.x as usize,
+                y: pos.y as usize,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
+    }
+}
+
+impl FromStr for Line {
+    type Err = Box<dyn Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points: Vec<Point> = s
+            .split(" -> ")
+            .map(|s| {
+                let mut parts = s.split(',');
+                Point {
+                    x: parts.next().unwrap().parse().unwrap(),
+                    y: parts.next().unwrap().parse().unwrap(),
+                }
+            })
+            .collect();
+        Ok(Line {
+            start: points[0],
+            end: points[1],
+        })
+    }
+}
+
+pub fn parse_input(input: String) -> Vec<Line> {
+    input.lines().map(|s| s.parse().unwrap()).collect()
+}
+
+impl From<Point> for Pos {
+    fn from(point: Point) -> Pos {
+        Pos::new(point.x as isize, point.y as isize)
+    }
+}
+
+impl From<Line> for Segment {
+    fn from(line: Line) -> Segment {
+        Segment::new(line.start.into(), line.end.into())
+    }
+}
+
+/// Counts the points covered by at least two of `lines`.
+pub fn overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    let segments: Vec<Segment> = lines.map(|&line| line.into()).collect();
+    count_overlaps(&segments)
+}
+
+pub fn part_1(input: &[Line]) -> usize {
+    overlaps(input.iter().filter(|line| !line.is_diag()))
+}
+
+pub fn part_2(input: &[Line]) -> usize {
+    overlaps(input.iter())
+}
+
+#[cfg(test)]
+mod day_5_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 5);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 5169);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 12);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 22083);
+    }
+}
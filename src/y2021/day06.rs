@@ -0,0 +1,71 @@
+pub const DAY: u8 = 6;
+
+pub type LanternfishPop = Vec<usize>;
+
+pub fn parse_input(input: String) -> LanternfishPop {
+    let mut lanternfish = Vec::from([0; 9]);
+    input
+        .trim()
+        .split(',')
+        .for_each(|timer| lanternfish[timer.parse::<usize>().unwrap()] += 1);
+    lanternfish
+}
+
+pub fn part_1(input: &LanternfishPop) -> usize {
+    (1..=80)
+        .fold(input.clone(), |pop, _| {
+            let mut pop = pop;
+            let birthing = pop.remove(0);
+            pop[6] += birthing;
+            pop.push(birthing);
+            pop
+        })
+        .iter()
+        .sum()
+}
+
+pub fn part_2(input: &LanternfishPop) -> usize {
+    (1..=256)
+        .fold(input.clone(), |pop, _| {
+            let mut pop = pop;
+            let birthing = pop.remove(0);
+            pop[6] += birthing;
+            pop.push(birthing);
+            pop
+        })
+        .iter()
+        .sum()
+}
+
+#[cfg(test)]
+mod day_6_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 5934);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 393019);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 26984457539);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 1757714216975);
+    }
+}
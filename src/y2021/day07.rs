@@ -0,0 +1,88 @@
+pub const DAY: u8 = 7;
+
+pub fn parse_input(input: String) -> Vec<usize> {
+    input
+        .trim()
+        .split(',')
+        .map(|pos| pos.parse::<usize>().unwrap())
+        .collect()
+}
+
+pub fn fuel_expended(input: &[usize], dest: usize) -> usize {
+    input.iter().fold(0, |acc, &pos| acc + pos.abs_diff(dest))
+}
+
+pub fn sum_1_to_n(n: usize) -> usize {
+    (n * (n + 1)) / 2
+}
+
+pub fn fuel_expended_part_2(input: &[usize], dest: usize) -> usize {
+    input
+        .iter()
+        .fold(0, |acc, &pos| acc + sum_1_to_n(pos.abs_diff(dest)))
+}
+
+pub fn part_1(input: &[usize]) -> usize {
+    let max = *input.iter().max().unwrap();
+    (0..=max)
+        .map(|dest| fuel_expended(input, dest))
+        .min()
+        .unwrap()
+}
+
+pub fn part_2(input: &[usize]) -> usize {
+    let max = *input.iter().max().unwrap();
+    (0..=max)
+        .map(|dest| fuel_expended_part_2(input, dest))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod day_7_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_fuel_expended() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(fuel_expended(&parsed, 2), 37);
+        assert_eq!(fuel_expended(&parsed, 1), 41);
+        assert_eq!(fuel_expended(&parsed, 3), 39);
+        assert_eq!(fuel_expended(&parsed, 10), 71);
+    }
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 37);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 325528);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 0);
+    }
+    #[test]
+    fn test_fuel_expended_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(fuel_expended_part_2(&parsed, 5), 168);
+        assert_eq!(fuel_expended_part_2(&parsed, 2), 206);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 0);
+    }
+}
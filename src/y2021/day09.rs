@@ -0,0 +1,71 @@
+use crate::grid::{Neighborhood, Pos, UsizeGrid as Grid};
+
+pub const DAY: u8 = 9;
+
+pub fn is_low_point(grid: &Grid, pos: Pos) -> bool {
+    grid.neighbor_cells(pos, &Neighborhood::VonNeumann)
+        .all(|(_, &neighbor)| grid[pos] < neighbor)
+}
+
+/// Basins are bounded by height 9, and each one flows down to a low point.
+pub fn basin_size(grid: &Grid, pos: Pos) -> usize {
+    grid.flood_fill(pos, &Neighborhood::VonNeumann, |_, &height| height != 9)
+        .len()
+}
+
+pub fn parse_input(input: String) -> Grid {
+    input.parse().unwrap()
+}
+
+pub fn part_1(input: &Grid) -> usize {
+    input
+        .positions()
+        .filter(|&pos| is_low_point(input, pos))
+        .map(|pos| input[pos] + 1)
+        .sum()
+}
+
+pub fn part_2(input: &Grid) -> usize {
+    let mut basins = input
+        .components(&Neighborhood::VonNeumann, |_, &height| height != 9)
+        .sizes;
+    basins.sort();
+    basins[basins.len() - 1] * basins[basins.len() - 2] * basins[basins.len() - 3]
+}
+
+#[cfg(test)]
+mod day_9_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 15);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 475);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 1134);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 0);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::automaton::Automaton;
+use crate::grid::{Color, Neighborhood};
+use std::fmt;
+
+pub const DAY: u8 = 11;
+
+#[derive(Clone)]
+pub struct Grid {
+    pub octopi: Automaton<usize>,
+}
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flashed = self
+            .octopi
+            .grid()
+            .render()
+            .highlight_where(|_, &energy| energy == 0, Color::BrightYellow);
+        write!(f, "{}", flashed)
+    }
+}
+
+pub fn step(grid: &mut Grid) -> usize {
+    // Increment energy level of all octopi. This stays sequential with the
+    // `parallel` feature, as 100 octopi are far too few to split over threads
+    grid.octopi
+        .grid_mut()
+        .iter_mut()
+        .for_each(|energy| *energy += 1);
+
+    // Flash high energy octopi, each flash raising the energy around it
+    let flashed = grid
+        .octopi
+        .cascade(|&energy| energy > 9, |energy| *energy += 1);
+
+    // Reset flashed octopi's energy to zero
+    flashed
+        .iter()
+        .for_each(|&octopus| grid.octopi.grid_mut()[octopus] = 0);
+
+    flashed.len()
+}
+
+pub fn parse_input(input: String) -> Grid {
+    Grid {
+        octopi: Automaton::new(input.parse().unwrap(), &Neighborhood::Moore),
+    }
+}
+
+pub fn part_1(input: &Grid) -> usize {
+    let mut grid = input.clone();
+    (1..=100).fold(0, |flashes, _| flashes + step(&mut grid))
+}
+
+pub fn part_2(input: &Grid) -> usize {
+    let all = input.octopi.grid().size();
+    let mut grid = input.clone();
+    (1..).find(|_| step(&mut grid) == all).unwrap()
+}
+
+#[cfg(test)]
+mod day_11_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 1656);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 1661);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 195);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 334);
+    }
+}
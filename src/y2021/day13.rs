@@ -0,0 +1,192 @@
+use crate::grid::{Grid, Pos};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const DAY: u8 = 13;
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Dot {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl FromStr for Dot {
+    type Err = Box<dyn Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<usize> = s
+            .split(',')
+            .map(|part| part.parse::<usize>().unwrap())
+            .collect();
+        Ok(Dot {
+            x: parts[0],
+            y: parts[1],
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Fold {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl FromStr for Fold {
+    type Err = Box<dyn Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('=').collect();
+        let line = parts[1].parse().unwrap();
+        Ok(match parts[0].contains('y') {
+            true => Fold::Vertical(line),
+            false => Fold::Horizontal(line),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct TransparentPaper {
+    pub dots: Grid<bool>,
+    pub folds: Vec<Fold>,
+}
+
+impl fmt::Display for TransparentPaper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rendered = self
+            .dots
+            .render_with(|_, &dot| String::from(if dot { "#" } else { " " }));
+        writeln!(f, "{}", rendered)
+    }
+}
+
+impl TransparentPaper {
+    pub fn fold(&mut self) {
+        let merge = |&kept: &bool, &folded: &bool| kept || folded;
+        self.dots = match self.folds.pop() {
+            Some(Fold::Vertical(line)) => self.dots.fold_up(line, merge),
+            Some(Fold::Horizontal(line)) => self.dots.fold_left(line, merge),
+            None => return,
+        };
+    }
+
+    pub fn dot_count(&self) -> usize {
+        self.dots.iter().filter(|&&dot| dot).count()
+    }
+}
+
+pub fn parse_input(input: String) -> TransparentPaper {
+    let mut parts = input.split("\n\n");
+    let dots: Vec<Dot> = parts
+        .next()
+        .unwrap()
+        .lines()
+        .map(|dot| dot.parse().unwrap())
+        .collect();
+    let folds: Vec<Fold> = parts
+        .next()
+        .unwrap()
+        .lines()
+        .rev()
+        .map(|fold| fold.parse().unwrap())
+        .collect();
+    // The paper reaches as far past the first fold as before it, even where
+    // there are no dots
+    let (mut width, mut height) = (1, 1);
+    for fold in &folds {
+        match *fold {
+            Fold::Vertical(line) => height = 2 * line + 1,
+            Fold::Horizontal(line) => width = 2 * line + 1,
+        }
+    }
+    for dot in &dots {
+        width = width.max(dot.x + 1);
+        height = height.max(dot.y + 1);
+    }
+    let mut paper = Grid::new(vec![false; width * height], width);
+    for dot in dots {
+        paper[Pos::new(dot.x as isize, dot.y as isize)] = true;
+    }
+    TransparentPaper { dots: paper, folds }
+}
+
+pub fn part_1(input: &TransparentPaper) -> usize {
+    let mut sheet = input.clone();
+    sheet.fold();
+    sheet.dot_count()
+}
+
+pub fn part_2(input: &TransparentPaper) -> String {
+    let mut sheet = input.clone();
+    while !sheet.folds.is_empty() {
+        sheet.fold();
+    }
+    sheet.to_string()
+}
+
+#[cfg(test)]
+mod day_13_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 17);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(
+            part_2(&parsed),
+            "#####\n#   #\n#   #\n#   #\n#####\n     \n     \n"
+        );
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 0);
+    }
+    #[test]
+    fn solution_part_2() {
+        // HECRZKPR
+        // #  # ####  ##  ###  #### #  # ###  ###
+        // #  # #    #  # #  #    # # #  #  # #  #
+        // #### ###  #    #  #   #  ##   #  # #  #
+        // #  # #    #    ###   #   # #  ###  ###
+        // #  # #    #  # # #  #    # #  #    # #
+        // #  # ####  ##  #  # #### #  # #    #  #
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed).len(), 246);
+    }
+}
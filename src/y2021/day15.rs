@@ -0,0 +1,93 @@
+use crate::grid::{search, Neighborhood, Pos, UsizeGrid as Grid};
+
+pub const DAY: u8 = 15;
+
+/// The lowest total risk of a path from `start` to `goal`, not counting the
+/// risk of `start` itself.
+pub fn pathfind(grid: &Grid, start: Pos, goal: Pos) -> usize {
+    search::dijkstra(grid, &Neighborhood::VonNeumann, start, goal, |_, &risk| {
+        Some(risk)
+    })
+    .unwrap()
+    .cost
+}
+
+/// The full cave is the grid tiled 5x5, with risk going up by 1 for each
+/// tile right or down and wrapping from 9 back to 1.
+pub fn enlarge_grid(grid: &Grid) -> Grid {
+    grid.tile(5, 5, |&risk, x, y| (risk + x + y - 1) % 9 + 1)
+}
+
+pub fn parse_input(input: String) -> Grid {
+    input.parse().unwrap()
+}
+
+/// Paths run from the top left to the bottom right of the cave.
+pub fn corners(grid: &Grid) -> (Pos, Pos) {
+    (
+        grid.pos_of(grid.first()).unwrap(),
+        grid.pos_of(grid.last()).unwrap(),
+    )
+}
+
+pub fn part_1(input: &Grid) -> usize {
+    let (start, goal) = corners(input);
+    pathfind(input, start, goal)
+}
+
+pub fn part_2(input: &Grid) -> usize {
+    let big_grid = enlarge_grid(input);
+    let (start, goal) = corners(&big_grid);
+    pathfind(&big_grid, start, goal)
+}
+
+#[cfg(test)]
+mod day_15_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 40);
+    }
+    #[test]
+    fn test_pathfind_markers() {
+        let (grid, markers) =
+            Grid::parse_with_markers("919\nS11\n91E", &[('S', 1), ('E', 1)], |c| {
+                c.to_digit(10).map(|d| d as usize).ok_or("not a digit")
+            })
+            .unwrap();
+        assert_eq!(pathfind(&grid, markers[&'S'][0], markers[&'E'][0]), 3);
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 415);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 315);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 2864);
+    }
+}
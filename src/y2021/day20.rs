@@ -0,0 +1,290 @@
+use crate::automaton::Automaton;
+use crate::grid::{BitGrid, Boundary, Grid, Offset, Window};
+use std::fmt;
+use std::str::FromStr;
+
+pub const DAY: u8 = 20;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pixel {
+    Light,
+    Dark,
+}
+impl FromStr for Pixel {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "#" => Ok(Self::Light),
+            "." => Ok(Self::Dark),
+            _ => Err("Unknown char"),
+        }
+    }
+}
+impl fmt::Display for Pixel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Light => "#",
+                Self::Dark => ".",
+            }
+        )
+    }
+}
+
+/// The image is a window on an infinite plane of background pixels.
+#[derive(Clone)]
+pub struct Image {
+    pub automaton: Automaton<Pixel>,
+}
+impl Image {
+    pub fn enhance(&mut self, algorithm: &[Pixel]) {
+        #[cfg(not(feature = "parallel"))]
+        self.automaton
+            .step_window(3, 3, |square| algorithm[pixels_to_num(square)]);
+        #[cfg(feature = "parallel")]
+        self.automaton
+            .par_step_window(3, 3, |square| algorithm[pixels_to_num(square)]);
+    }
+
+    pub fn count_lit(&self) -> usize {
+        self.automaton
+            .grid()
+            .iter()
+            .filter(|&&p| p == Pixel::Light)
+            .count()
+    }
+}
+impl FromStr for Image {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<Pixel> = s.parse().map_err(|_| "Invalid image")?;
+        Ok(Image {
+            automaton: Automaton::infinite_windowed(grid, Pixel::Dark),
+        })
+    }
+}
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.automaton.grid())
+    }
+}
+
+/// Reads the pixels of a window as a binary number, top left first.
+pub fn pixels_to_num(window: &Window<'_, Pixel>) -> usize {
+    window.cells().fold(0, |num, pixel| {
+        num << 1 | (pixel == Some(&Pixel::Light)) as usize
+    })
+}
+
+/// A faster `Image`, enhancing whole rows of pixels at once. It holds which
+/// pixels of the window differ from the background, so that the plane past
+/// the window is always unset. Each step shifts those bits once per pixel of
+/// the square and picks out the cells that light up with bitwise operations,
+/// giving the same pixels as `Image::enhance`.
+///
+/// The parts use this even with the `parallel` feature. An image of at most
+/// 200x200 pixels splits into at most 4 chunks for `par_step_window`, which
+/// is over 4 times slower per core than this, so it can't catch up.
+#[derive(Clone)]
+pub struct BitImage {
+    changed: BitGrid,
+    background: Pixel,
+}
+impl BitImage {
+    pub fn enhance(&mut self, algorithm: &[Pixel]) {
+        let changed = self.changed.pad(1);
+        let (width, height) = (changed.width(), changed.height());
+        // The pixel at each offset of every square, top left first, as grids
+        let squares: Vec<BitGrid> = (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Offset::new(-dx, -dy)))
+            .map(|offset| {
+                let shifted = changed.shift(offset);
+                match self.background {
+                    Pixel::Light => !&shifted,
+                    Pixel::Dark => shifted,
+                }
+            })
+            .collect();
+        let background = match self.background {
+            Pixel::Light => algorithm[511],
+            Pixel::Dark => algorithm[0],
+        };
+        let table: Vec<bool> = algorithm.iter().map(|&p| p != background).collect();
+        self.changed = select(&squares, &table).into_bits(width, height);
+        self.background = background;
+    }
+
+    /// The pixels of the window.
+    pub fn pixels(&self) -> BitGrid {
+        match self.background {
+            Pixel::Light => !&self.changed,
+            Pixel::Dark => self.changed.clone(),
+        }
+    }
+
+    pub fn count_lit(&self) -> usize {
+        self.pixels().count_ones()
+    }
+}
+impl From<&Image> for BitImage {
+    fn from(image: &Image) -> Self {
+        let background = match image.automaton.boundary() {
+            Boundary::Fixed(background) => *background,
+            _ => unreachable!("an image is always on an infinite plane"),
+        };
+        let grid = image.automaton.grid();
+        let changed = Grid::new(
+            grid.iter().map(|&pixel| pixel != background).collect(),
+            grid.width(),
+        );
+        BitImage {
+            changed: BitGrid::from(&changed),
+            background,
+        }
+    }
+}
+impl fmt::Display for BitImage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pixels())
+    }
+}
+
+/// Either every cell or none of them, or a grid of the cells in between.
+enum Plane {
+    Const(bool),
+    Bits(BitGrid),
+}
+impl Plane {
+    fn into_bits(self, width: usize, height: usize) -> BitGrid {
+        match self {
+            Plane::Const(false) => BitGrid::new(width, height),
+            Plane::Const(true) => !&BitGrid::new(width, height),
+            Plane::Bits(bits) => bits,
+        }
+    }
+}
+
+/// The cells whose pixels in `squares`, read as a binary number, index a true
+/// entry of `table`. The table is split in half on the leading pixel, so every
+/// cell is looked up at once with a few bitwise operations per entry.
+fn select(squares: &[BitGrid], table: &[bool]) -> Plane {
+    let Some((pixel, rest)) = squares.split_first() else {
+        return Plane::Const(table[0]);
+    };
+    let (unset, set) = table.split_at(table.len() / 2);
+    match (select(rest, set), select(rest, unset)) {
+        (Plane::Const(set), Plane::Const(unset)) if set == unset => Plane::Const(set),
+        (Plane::Const(true), Plane::Const(false)) => Plane::Bits(pixel.clone()),
+        (Plane::Const(false), Plane::Const(true)) => Plane::Bits(!pixel),
+        (set, unset) => {
+            let (width, height) = (pixel.width(), pixel.height());
+            let set = pixel & &set.into_bits(width, height);
+            let unset = &!pixel & &unset.into_bits(width, height);
+            Plane::Bits(&set | &unset)
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> (Vec<Pixel>, Image) {
+    let mut parts = input.split("\n\n");
+    let algorithm = parts
+        .next()
+        .unwrap()
+        .trim()
+        .split("")
+        .filter(|s| s.len() == 1)
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let image = parts.next().unwrap().parse().unwrap();
+    (algorithm, image)
+}
+
+pub fn part_1(input: &(Vec<Pixel>, Image)) -> usize {
+    let algorithm = &input.0;
+    let mut image = BitImage::from(&input.1);
+    image.enhance(algorithm);
+    image.enhance(algorithm);
+    image.count_lit()
+}
+
+pub fn part_2(input: &(Vec<Pixel>, Image)) -> usize {
+    let algorithm = &input.0;
+    let mut image = BitImage::from(&input.1);
+    for _ in 0..50 {
+        image.enhance(algorithm);
+    }
+    image.count_lit()
+}
+
+#[cfg(test)]
+mod day_20_tests {
+    use super::*;
+    use crate::get_input;
+
+    const TEST_INPUT: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn test_part_1() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 35);
+    }
+    #[test]
+    fn test_flashing_background() {
+        let input = format!("#{}\n\n.", ".".repeat(511));
+        let (algorithm, image) = parse_input(input);
+        let mut image = BitImage::from(&image);
+        image.enhance(&algorithm);
+        assert_eq!(image.count_lit(), 9);
+        image.enhance(&algorithm);
+        assert_eq!(image.count_lit(), 0);
+        assert_eq!(image.to_string(), ".....\n".repeat(5).trim());
+    }
+    #[test]
+    fn test_bit_image() {
+        // The test algorithm, and one that makes the background flash
+        let flashing: String = TEST_INPUT
+            .char_indices()
+            .map(|(i, c)| match i {
+                0 => '#',
+                511 => '.',
+                _ => c,
+            })
+            .collect();
+        for input in [String::from(TEST_INPUT), flashing] {
+            let (algorithm, mut image) = parse_input(input);
+            let mut bits = BitImage::from(&image);
+            for _ in 0..5 {
+                image.enhance(&algorithm);
+                bits.enhance(&algorithm);
+                assert_eq!(bits.to_string(), image.to_string());
+            }
+        }
+    }
+    #[test]
+    fn solution_part_1() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_1(&parsed), 5268);
+    }
+    #[test]
+    fn test_part_2() {
+        let input = String::from(TEST_INPUT);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 3351);
+    }
+    #[test]
+    fn solution_part_2() {
+        let input = get_input(DAY);
+        let parsed = parse_input(input);
+        assert_eq!(part_2(&parsed), 16875);
+    }
+}
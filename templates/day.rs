@@ -1,33 +1,25 @@
-use aoc::*;
+// Template for a new day: copy to src/y2021/dayNN.rs, add it to the modules
+// and `solvers!` in src/y2021.rs, and add a src/bin/dayN.rs wrapper calling
+// `aoc::y2021::run(DAY)`.
 
-const DAY: u8 = 0;
+pub const DAY: u8 = 0;
 
-fn main() {
-    println!("==== Day {} ====", DAY);
-    let input = get_input(DAY);
-    println!("Input size: {}", input.len());
-    let parsed_input = parse_input(input);
-    let part_1 = part_1(&parsed_input);
-    println!("Part 1: {}", part_1);
-    let part_2 = part_2(&parsed_input);
-    println!("Part 2: {}", part_2);
+pub fn parse_input(input: String) -> Vec<String> {
+    input.lines().map(String::from).collect()
 }
 
-fn parse_input(input: String) -> Vec<String> {
+pub fn part_1(_input: &[String]) -> usize {
     unimplemented!()
 }
 
-fn part_1(input: &Vec<String>) -> usize {
-    unimplemented!()
-}
-
-fn part_2(input: &Vec<String>) -> usize {
+pub fn part_2(_input: &[String]) -> usize {
     unimplemented!()
 }
 
 #[cfg(test)]
 mod day_0_tests {
     use super::*;
+    use crate::get_input;
 
     const TEST_INPUT: &str = "";
 